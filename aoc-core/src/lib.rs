@@ -0,0 +1,194 @@
+#[cfg(feature = "eyre")]
+use color_eyre::eyre::{Result, WrapErr as _};
+use std::io::{BufRead, BufReader, Read};
+#[cfg(feature = "eyre")]
+use std::path::Path;
+
+#[cfg(feature = "eyre")]
+/// A day's puzzle, both parts of which are answered from the same parsed input
+pub trait Solution {
+    type Input;
+
+    fn parse(input: &str) -> Result<Self::Input>;
+    fn part1(input: &Self::Input) -> Result<String>;
+    fn part2(input: &Self::Input) -> Result<String>;
+
+    /// Parses `input` without solving it, returning how many lines it holds
+    fn validate(input: &str) -> Result<usize> {
+        Self::parse(input)?;
+        Ok(input.lines().count())
+    }
+}
+
+/// Logs the solutions' `debug` spans to stderr, along with each phase's duration when it closes
+#[cfg(feature = "trace")]
+pub fn init_tracing() {
+    tracing_subscriber::fmt()
+        .with_max_level(tracing_subscriber::filter::LevelFilter::DEBUG)
+        .with_span_events(tracing_subscriber::fmt::format::FmtSpan::CLOSE)
+        .with_writer(std::io::stderr)
+        .init();
+}
+
+/// Buffers `reader`, decompressing it on the fly when `gzip` is set
+pub fn input_reader<'a>(reader: impl Read + 'a, gzip: bool) -> Box<dyn BufRead + 'a> {
+    if gzip {
+        Box::new(BufReader::new(flate2::read::MultiGzDecoder::new(reader)))
+    } else {
+        Box::new(BufReader::new(reader))
+    }
+}
+
+#[cfg(feature = "eyre")]
+/// Opens `path` for buffered reading, transparently decompressing `.gz` files
+pub fn open(path: impl AsRef<Path>) -> Result<Box<dyn BufRead>> {
+    open_with(path, false)
+}
+
+#[cfg(feature = "eyre")]
+/// Same as `open`, but also decompresses files without a `.gz` extension when `gzip` is set
+pub fn open_with(path: impl AsRef<Path>, gzip: bool) -> Result<Box<dyn BufRead>> {
+    let path = path.as_ref();
+    let file = std::fs::File::open(path)
+        .wrap_err_with(|| format!("Cannot open input file {}", path.display()))?;
+    let gzip = gzip || path.extension().is_some_and(|ext| ext == "gz");
+    Ok(input_reader(file, gzip))
+}
+
+#[cfg(feature = "eyre")]
+pub fn read_lines(path: impl AsRef<Path>) -> Result<impl Iterator<Item = std::io::Result<String>>> {
+    Ok(open(path)?.lines())
+}
+
+#[cfg(feature = "eyre")]
+pub fn read_to_string(path: impl AsRef<Path>) -> Result<String> {
+    let path = path.as_ref();
+    std::io::read_to_string(open(path)?)
+        .wrap_err_with(|| format!("Cannot read input file {}", path.display()))
+}
+
+/// Iterator over groups of consecutive non-blank lines, see `groups`
+#[derive(Debug)]
+pub struct Groups<I> {
+    lines: std::iter::Enumerate<I>,
+    done: bool,
+}
+
+impl<I, S, E> Iterator for Groups<I>
+where
+    I: Iterator<Item = std::result::Result<S, E>>,
+    S: AsRef<str>,
+{
+    type Item = std::result::Result<Vec<(usize, S)>, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        let mut group = vec![];
+        loop {
+            match self.lines.next() {
+                // The last group isn't followed by a blank line when the input has no trailing newline
+                None => {
+                    self.done = true;
+                    return (!group.is_empty()).then_some(Ok(group));
+                }
+                Some((_, Err(e))) => {
+                    self.done = true;
+                    return Some(Err(e));
+                }
+                Some((_, Ok(line))) if line.as_ref().trim().is_empty() => return Some(Ok(group)),
+                Some((idx, Ok(line))) => group.push((idx + 1, line)),
+            }
+        }
+    }
+}
+
+/// Lazily splits `lines` into groups on blank lines, each line coming with its 1-based number.
+///
+/// Every blank line closes a group, so consecutive blank lines yield empty groups, while the
+/// last group is yielded whether the input ends with a blank line or not.
+pub fn groups<I, S, E>(lines: I) -> Groups<I::IntoIter>
+where
+    I: IntoIterator<Item = std::result::Result<S, E>>,
+    S: AsRef<str>,
+{
+    Groups {
+        lines: lines.into_iter().enumerate(),
+        done: false,
+    }
+}
+
+/// Reads `reader` fully, split into groups of lines on blank lines. See `groups`.
+pub fn read_groups<R: BufRead>(reader: R) -> std::io::Result<Vec<Vec<String>>> {
+    groups(reader.lines())
+        .map(|group| Ok(group?.into_iter().map(|(_, line)| line).collect()))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn read_groups_with_trailing_blank_line() {
+        let input = std::io::Cursor::new("1000\n2000\n\n4000\n\n");
+        assert_eq!(
+            read_groups(input).unwrap(),
+            vec![vec!["1000", "2000"], vec!["4000"]]
+        );
+    }
+
+    #[test]
+    fn read_groups_without_trailing_blank_line() {
+        let input = std::io::Cursor::new("1000\n2000\n\n4000");
+        assert_eq!(
+            read_groups(input).unwrap(),
+            vec![vec!["1000", "2000"], vec!["4000"]]
+        );
+
+        let input = std::io::Cursor::new("1000\n2000\n\n4000\n");
+        assert_eq!(
+            read_groups(input).unwrap(),
+            vec![vec!["1000", "2000"], vec!["4000"]]
+        );
+    }
+
+    #[test]
+    fn read_groups_keeps_empty_groups() {
+        let input = std::io::Cursor::new("1000\n\n\n4000\n");
+        assert_eq!(
+            read_groups(input).unwrap(),
+            vec![vec!["1000"], vec![], vec!["4000"]]
+        );
+        assert!(read_groups(std::io::Cursor::new("")).unwrap().is_empty());
+    }
+
+    #[test]
+    fn input_reader_decompresses_gzip() {
+        use std::io::Write as _;
+
+        let plaintext = "1000\n2000\n\n4000\n";
+        let mut encoder = flate2::write::GzEncoder::new(vec![], flate2::Compression::default());
+        encoder.write_all(plaintext.as_bytes()).unwrap();
+        let gzipped = encoder.finish().unwrap();
+
+        let lines: Vec<String> = input_reader(gzipped.as_slice(), true)
+            .lines()
+            .map(Result::unwrap)
+            .collect();
+        assert_eq!(lines.len(), plaintext.lines().count());
+        assert_eq!(lines, plaintext.lines().collect::<Vec<_>>());
+
+        let lines = input_reader(plaintext.as_bytes(), false).lines().count();
+        assert_eq!(lines, plaintext.lines().count());
+    }
+
+    #[test]
+    fn groups_number_lines() {
+        let lines = ["a", "b", "", "c"].map(Ok::<_, std::convert::Infallible>);
+        let groups: Vec<Vec<(usize, &str)>> = groups(lines).map(Result::unwrap).collect();
+        assert_eq!(groups, vec![vec![(1, "a"), (2, "b")], vec![(4, "c")]]);
+    }
+}
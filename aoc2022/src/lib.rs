@@ -0,0 +1,125 @@
+use aoc_core::Solution;
+use color_eyre::eyre::{eyre, Result};
+use std::path::{Path, PathBuf};
+
+#[derive(Debug, clap::Parser)]
+#[command(name = "aoc2022", about = "Advent of Code 2022 answers")]
+pub struct Cli {
+    #[command(subcommand)]
+    pub day: Day,
+}
+
+#[derive(Debug, PartialEq, Eq, clap::Subcommand)]
+pub enum Day {
+    /// Calorie counting
+    Day1(DayArgs),
+    /// Rock paper scissors
+    Day2(DayArgs),
+    /// Rucksack reorganization
+    Day3(DayArgs),
+    /// Camp cleanup
+    Day4(DayArgs),
+    /// Supply stacks
+    Day5(DayArgs),
+    /// Tuning trouble
+    Day6(DayArgs),
+    /// No space left on device
+    Day7(DayArgs),
+    /// Treetop tree house
+    Day8(DayArgs),
+    /// Rope bridge
+    Day9(DayArgs),
+    /// Cathode-ray tube
+    Day10(DayArgs),
+}
+
+#[derive(Debug, PartialEq, Eq, clap::Args)]
+pub struct DayArgs {
+    /// Puzzle input file, `-` reading it from stdin. Defaults to the day's bundled input, if any
+    #[arg(short, long)]
+    pub input: Option<PathBuf>,
+    /// Part to answer, both being answered when omitted
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    pub part: Option<u8>,
+    /// Input is gzip-compressed, which is implied for `.gz` input files
+    #[arg(long)]
+    pub gzip: bool,
+    /// Only checks that the input parses, without answering any part
+    #[arg(long, conflicts_with = "part")]
+    pub validate: bool,
+}
+
+impl DayArgs {
+    fn read_input(&self, bundled_input: Option<&str>) -> Result<String> {
+        let reader = match self.input.as_deref() {
+            Some(path) if path == Path::new("-") => {
+                aoc_core::input_reader(std::io::stdin().lock(), self.gzip)
+            }
+            Some(path) => aoc_core::open_with(path, self.gzip)?,
+            None => {
+                let path = bundled_input
+                    .ok_or_else(|| eyre!("This day has no bundled input, pass one with --input"))?;
+                aoc_core::open_with(path, self.gzip)?
+            }
+        };
+        Ok(std::io::read_to_string(reader)?)
+    }
+
+    fn solve<S: Solution>(
+        &self,
+        bundled_input: Option<&str>,
+        out: &mut impl std::io::Write,
+    ) -> Result<()> {
+        let input = self.read_input(bundled_input)?;
+        if self.validate {
+            let lines = S::validate(&input)?;
+            writeln!(out, "Input is valid, {lines} lines")?;
+            return Ok(());
+        }
+
+        let input = S::parse(&input)?;
+        if self.part != Some(2) {
+            writeln!(out, "Part 1: {}", S::part1(&input)?)?;
+        }
+        if self.part != Some(1) {
+            writeln!(out, "Part 2: {}", S::part2(&input)?)?;
+        }
+        Ok(())
+    }
+}
+
+impl Cli {
+    /// Writes the answers to the requested day's parts into `out`
+    pub fn run(&self, out: &mut impl std::io::Write) -> Result<()> {
+        match &self.day {
+            Day::Day1(args) => args.solve::<day1::Day1>(
+                Some(concat!(
+                    env!("CARGO_MANIFEST_DIR"),
+                    "/../day1/src/elf_list.txt"
+                )),
+                out,
+            ),
+            Day::Day2(args) => args.solve::<day2::Day2>(
+                Some(concat!(
+                    env!("CARGO_MANIFEST_DIR"),
+                    "/../day2/src/rps_strategy_guide.txt"
+                )),
+                out,
+            ),
+            Day::Day3(args) => args.solve::<day3::Day3>(
+                Some(concat!(
+                    env!("CARGO_MANIFEST_DIR"),
+                    "/../day3/src/rucksack_list.txt"
+                )),
+                out,
+            ),
+            Day::Day4(args) => args.solve::<day4::Day4>(None, out),
+            Day::Day5(args) => args.solve::<day5::Day5>(None, out),
+            Day::Day6(args) => args.solve::<day6::Day6>(None, out),
+            Day::Day7(args) => args.solve::<day7::Day7>(None, out),
+            Day::Day8(args) => args.solve::<day8::Day8>(None, out),
+            Day::Day9(args) => args.solve::<day9::Day9>(None, out),
+            Day::Day10(args) => args.solve::<day10::Day10>(None, out),
+        }
+    }
+}
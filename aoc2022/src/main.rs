@@ -0,0 +1,11 @@
+use aoc2022::Cli;
+use clap::Parser as _;
+use color_eyre::eyre::Result;
+
+fn main() -> Result<()> {
+    #[cfg(feature = "trace")]
+    aoc_core::init_tracing();
+    color_eyre::install()?;
+
+    Cli::parse().run(&mut std::io::stdout().lock())
+}
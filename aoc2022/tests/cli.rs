@@ -0,0 +1,88 @@
+use aoc2022::{Cli, Day, DayArgs};
+use clap::Parser as _;
+
+#[test]
+fn parses_day_with_input_and_part() {
+    let cli =
+        Cli::try_parse_from(["aoc2022", "day1", "--input", "elves.txt", "--part", "2"]).unwrap();
+    assert_eq!(
+        cli.day,
+        Day::Day1(DayArgs {
+            input: Some("elves.txt".into()),
+            part: Some(2),
+            gzip: false,
+            validate: false,
+        })
+    );
+
+    let cli = Cli::try_parse_from(["aoc2022", "day3", "-i", "-", "--gzip"]).unwrap();
+    assert_eq!(
+        cli.day,
+        Day::Day3(DayArgs {
+            input: Some("-".into()),
+            part: None,
+            gzip: true,
+            validate: false,
+        })
+    );
+}
+
+#[test]
+fn rejects_unknown_day_and_part() {
+    assert!(Cli::try_parse_from(["aoc2022", "day42"]).is_err());
+    assert!(Cli::try_parse_from(["aoc2022", "day2", "--part", "3"]).is_err());
+    assert!(Cli::try_parse_from(["aoc2022"]).is_err());
+    assert!(Cli::try_parse_from(["aoc2022", "day1", "--validate", "-p", "1"]).is_err());
+}
+
+#[test]
+fn requires_input_without_bundled_one() {
+    let cli = Cli::try_parse_from(["aoc2022", "day4"]).unwrap();
+    let err = cli.run(&mut vec![]).unwrap_err();
+    assert!(err.to_string().contains("--input"));
+}
+
+#[test]
+fn validates_input_without_solving() {
+    let cli = Cli::try_parse_from(["aoc2022", "day3", "--validate"]).unwrap();
+    let mut out = vec![];
+    cli.run(&mut out).unwrap();
+    assert_eq!(
+        String::from_utf8(out).unwrap(),
+        "Input is valid, 300 lines\n"
+    );
+
+    let path = std::env::temp_dir().join(format!("aoc2022-validate-{}.txt", std::process::id()));
+    std::fs::write(
+        &path,
+        "vJrwpWtwJgWrhcsFMMfFFhFp\njqHRNqRjqzjGDL1GLrsFMfFZSrLrFZsSL\n",
+    )
+    .unwrap();
+    let cli = Cli::try_parse_from([
+        "aoc2022".as_ref(),
+        "day3".as_ref(),
+        "--validate".as_ref(),
+        "--input".as_ref(),
+        path.as_os_str(),
+    ])
+    .unwrap();
+    let err = cli.run(&mut vec![]).unwrap_err();
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(err.to_string(), "Invalid rucksack on line 2");
+}
+
+#[test]
+fn answers_both_parts_from_bundled_input() {
+    let cli = Cli::try_parse_from(["aoc2022", "day3"]).unwrap();
+    let mut out = vec![];
+    cli.run(&mut out).unwrap();
+    assert_eq!(
+        String::from_utf8(out).unwrap(),
+        "Part 1: 7990\nPart 2: 2602\n"
+    );
+
+    let cli = Cli::try_parse_from(["aoc2022", "day2", "-p", "1"]).unwrap();
+    let mut out = vec![];
+    cli.run(&mut out).unwrap();
+    assert_eq!(String::from_utf8(out).unwrap(), "Part 1: 12794\n");
+}
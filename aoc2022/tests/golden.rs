@@ -0,0 +1,85 @@
+//! Locks in each day's answers, real ones for the days whose puzzle input is bundled and the
+//! brief's ones for the others
+
+use aoc_core::Solution;
+
+fn answers<S: Solution>(input: &str) -> (String, String) {
+    let input = S::parse(input).unwrap();
+    (S::part1(&input).unwrap(), S::part2(&input).unwrap())
+}
+
+fn assert_answers<S: Solution>(input: &str, part1: &str, part2: &str) {
+    assert_eq!(answers::<S>(input), (part1.to_owned(), part2.to_owned()));
+}
+
+#[test]
+fn day1() {
+    assert_answers::<day1::Day1>(
+        include_str!("../../day1/src/elf_list.txt"),
+        "67658",
+        "200158",
+    );
+}
+
+#[test]
+fn day2() {
+    assert_answers::<day2::Day2>(
+        include_str!("../../day2/src/rps_strategy_guide.txt"),
+        "12794",
+        "14979",
+    );
+}
+
+#[test]
+fn day3() {
+    assert_answers::<day3::Day3>(
+        include_str!("../../day3/src/rucksack_list.txt"),
+        "7990",
+        "2602",
+    );
+}
+
+#[test]
+fn day4() {
+    assert_answers::<day4::Day4>(include_str!("fixtures/day4.txt"), "2", "4");
+}
+
+#[test]
+fn day5() {
+    assert_answers::<day5::Day5>(include_str!("fixtures/day5.txt"), "CMZ", "MCD");
+}
+
+#[test]
+fn day6() {
+    assert_answers::<day6::Day6>(include_str!("fixtures/day6.txt"), "7", "19");
+}
+
+#[test]
+fn day7() {
+    assert_answers::<day7::Day7>(include_str!("fixtures/day7.txt"), "95437", "24933642");
+}
+
+#[test]
+fn day8() {
+    assert_answers::<day8::Day8>(include_str!("fixtures/day8.txt"), "21", "8");
+}
+
+#[test]
+fn day9() {
+    assert_answers::<day9::Day9>(include_str!("fixtures/day9.txt"), "13", "1");
+}
+
+#[test]
+fn day10() {
+    assert_answers::<day10::Day10>(
+        include_str!("../../day10/src/brief_program.txt"),
+        "13140",
+        "
+##..##..##..##..##..##..##..##..##..##..
+###...###...###...###...###...###...###.
+####....####....####....####....####....
+#####.....#####.....#####.....#####.....
+######......######......######......####
+#######.......#######.......#######.....",
+    );
+}
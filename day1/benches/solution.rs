@@ -0,0 +1,17 @@
+use aoc_core::Solution;
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use day1::Day1;
+
+/// Brief example repeated, so that the benches don't need the real puzzle input
+const FIXTURE: &str = include_str!("fixture.txt");
+
+fn solution(c: &mut Criterion) {
+    let input = Day1::parse(FIXTURE).unwrap();
+
+    c.bench_function("day1 parse", |b| b.iter(|| Day1::parse(black_box(FIXTURE))));
+    c.bench_function("day1 part1", |b| b.iter(|| Day1::part1(black_box(&input))));
+    c.bench_function("day1 part2", |b| b.iter(|| Day1::part2(black_box(&input))));
+}
+
+criterion_group!(benches, solution);
+criterion_main!(benches);
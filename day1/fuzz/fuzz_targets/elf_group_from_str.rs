@@ -0,0 +1,20 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+// Any input must either parse or be rejected, and parsed elves must be rankable, never panicking
+fuzz_target!(|data: &[u8]| {
+    let Ok(input) = std::str::from_utf8(data) else {
+        return;
+    };
+    let Ok(elves) = input.parse::<day1::ElfGroup>() else {
+        return;
+    };
+
+    if let Some(chad_elf) = elves.elf_with_most_calories() {
+        let _ = chad_elf.try_total_calories_carried();
+    }
+    let _ = elves.top_3_elves_calories();
+    let _ = elves.ranked();
+    let _ = elves.calorie_statistics();
+});
@@ -0,0 +1,1038 @@
+pub type CalorieValue = u64;
+
+#[derive(Debug, thiserror::Error)]
+pub enum Day1Error {
+    #[error("Invalid calorie value `{value}` on line {line}")]
+    NonNumericCalorie {
+        line: usize,
+        value: String,
+        #[source]
+        source: std::num::ParseIntError,
+    },
+    #[error(
+        "Elf #{id} carries too many calories to count!{}",
+        overflow_location(overflowed_at)
+    )]
+    CalorieOverflow {
+        id: u64,
+        overflowed_at: Option<String>,
+    },
+    #[error("Elves list is empty!")]
+    NoElves,
+    #[error("Elves must carry at least one food item")]
+    EmptyElf,
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+}
+
+fn overflow_location(overflowed_at: &Option<String>) -> String {
+    overflowed_at
+        .as_ref()
+        .map(|label| format!(" (overflowed at {label})"))
+        .unwrap_or_default()
+}
+
+impl From<std::convert::Infallible> for Day1Error {
+    fn from(e: std::convert::Infallible) -> Self {
+        match e {}
+    }
+}
+
+pub type Result<T, E = Day1Error> = std::result::Result<T, E>;
+
+/// Text of a `#` comment line, starting right after the `#`
+fn comment(line: &str) -> Option<&str> {
+    line.trim_start().strip_prefix('#')
+}
+
+/// Parses the calorie value found on the 1-based `line`, keeping both around on failure.
+/// Every parser goes through here, so that they all ignore the same surrounding whitespace,
+/// `\r` of `\r\n` line endings included.
+fn parse_calorie_value(line: usize, value: &str) -> Result<CalorieValue> {
+    let value = value.trim();
+    value
+        .parse()
+        .map_err(|source| Day1Error::NonNumericCalorie {
+            line,
+            value: value.to_owned(),
+            source,
+        })
+}
+
+#[derive(Debug)]
+pub struct Food {
+    pub calorie_value: CalorieValue,
+    /// Where this food comes from, i.e. its line in the parsed input
+    pub label: Option<String>,
+}
+
+impl Food {
+    pub fn new(calorie_value: CalorieValue) -> Self {
+        Self {
+            calorie_value,
+            label: None,
+        }
+    }
+
+    pub fn with_label(calorie_value: CalorieValue, label: impl Into<String>) -> Self {
+        Self {
+            calorie_value,
+            label: Some(label.into()),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Food {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.calorie_value.serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Food {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        CalorieValue::deserialize(deserializer).map(Self::new)
+    }
+}
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Elf {
+    pub id: u64,
+    /// Given by a `# name` comment heading the elf's food in the parsed input
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub name: Option<String>,
+    pub food_carried: Vec<Food>,
+}
+
+impl Elf {
+    pub fn new(id: u64, food_carried: &[CalorieValue]) -> Self {
+        Self::with_food(
+            id,
+            food_carried
+                .iter()
+                .map(|calorie_value| Food::new(*calorie_value))
+                .collect(),
+        )
+    }
+
+    pub fn with_food(id: u64, food_carried: Vec<Food>) -> Self {
+        Self {
+            id,
+            name: None,
+            food_carried,
+        }
+    }
+
+    /// Saturates at `CalorieValue::MAX` instead of overflowing
+    pub fn total_calories_carried(&self) -> CalorieValue {
+        self.food_carried.iter().fold(0u64, |acc, food: &Food| {
+            acc.saturating_add(food.calorie_value)
+        })
+    }
+
+    pub fn try_total_calories_carried(&self) -> Result<CalorieValue> {
+        self.food_carried.iter().try_fold(0u64, |acc, food: &Food| {
+            acc.checked_add(food.calorie_value)
+                .ok_or_else(|| Day1Error::CalorieOverflow {
+                    id: self.id,
+                    overflowed_at: food.label.clone(),
+                })
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CalorieStats {
+    pub min: CalorieValue,
+    pub max: CalorieValue,
+    pub mean: f64,
+    pub median: f64,
+    /// Saturates at `CalorieValue::MAX`, unlike `mean` which is worked out from the exact sum
+    pub total: CalorieValue,
+}
+
+#[derive(Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ElfGroup(Vec<Elf>);
+
+impl ElfGroup {
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn iter(&self) -> std::slice::Iter<'_, Elf> {
+        self.0.iter()
+    }
+
+    pub fn add_elf(&mut self, food_carried: &[CalorieValue]) -> &mut Self {
+        let new_id = self.0.len() + 1;
+        self.0.push(Elf::new(new_id as u64, food_carried));
+        self
+    }
+
+    /// Same as `add_elf`, but refuses elves carrying nothing, which would skew rankings
+    pub fn try_add_elf(&mut self, food_carried: &[CalorieValue]) -> Result<&mut Self> {
+        if food_carried.is_empty() {
+            return Err(Day1Error::EmptyElf);
+        }
+        Ok(self.add_elf(food_carried))
+    }
+
+    pub fn add_elf_with_food(&mut self, food_carried: Vec<Food>) -> &mut Self {
+        let new_id = self.0.len() + 1;
+        self.0.push(Elf::with_food(new_id as u64, food_carried));
+        self
+    }
+
+    /// Appends `other`'s elves, renumbered to follow this group's highest id so that ids stay
+    /// unique
+    pub fn merge(&mut self, other: ElfGroup) {
+        let mut last_id = self.0.iter().map(|elf| elf.id).max().unwrap_or(0);
+        self.0.extend(other.0.into_iter().map(|mut elf| {
+            last_id += 1;
+            elf.id = last_id;
+            elf
+        }));
+    }
+
+    /// Lazily yields `(id, total_calories_carried)` for every elf
+    pub fn totals(&self) -> impl Iterator<Item = (u64, CalorieValue)> + '_ {
+        self.0
+            .iter()
+            .map(|elf| (elf.id, elf.total_calories_carried()))
+    }
+
+    /// Per elf id, the calories to receive (positive) or shed (negative) to carry `target`.
+    /// Deltas beyond `i64`'s range saturate.
+    pub fn rebalance_plan(&self, target: CalorieValue) -> Vec<(u64, i64)> {
+        self.totals()
+            .map(|(id, total)| {
+                let delta = (i128::from(target) - i128::from(total))
+                    .clamp(i64::MIN.into(), i64::MAX.into());
+                (id, delta as i64)
+            })
+            .collect()
+    }
+
+    pub fn elf_with_most_calories(&self) -> Option<&Elf> {
+        self.0
+            .iter()
+            .zip(self.totals())
+            .max_by_key(|&(_, (_, total))| total)
+            .map(|(elf, _)| elf)
+    }
+
+    pub fn elf_with_least_calories(&self) -> Option<&Elf> {
+        self.0
+            .iter()
+            .zip(self.totals())
+            .min_by_key(|&(_, (id, total))| (total, id))
+            .map(|(elf, _)| elf)
+    }
+
+    /// Splits elves into those carrying at least `threshold` calories and those carrying less,
+    /// both ordered by id
+    pub fn partition_by_threshold(&self, threshold: CalorieValue) -> (Vec<&Elf>, Vec<&Elf>) {
+        let mut elves: Vec<&Elf> = self.0.iter().collect();
+        elves.sort_by_key(|elf| elf.id);
+        elves
+            .into_iter()
+            .partition(|elf| elf.total_calories_carried() >= threshold)
+    }
+
+    /// Returns the `n` elves carrying the most calories, ties being broken by lowest id first
+    pub fn top_n_elves(&self, n: usize) -> Vec<&Elf> {
+        let mut elves: Vec<(&Elf, (u64, CalorieValue))> =
+            self.0.iter().zip(self.totals()).collect();
+        elves.sort_by_key(|&(_, (id, total))| (std::cmp::Reverse(total), id));
+        elves.into_iter().take(n).map(|(elf, _)| elf).collect()
+    }
+
+    /// Ranks every elf by descending calories carried, using standard competition ranking:
+    /// tied elves share the same rank and the following rank is skipped accordingly
+    /// (i.e. two elves ranked 1st are followed by the 3rd)
+    pub fn ranked(&self) -> Vec<(u32, &Elf)> {
+        let mut ranked: Vec<(u32, &Elf)> = Vec::with_capacity(self.0.len());
+        for (pos, elf) in self.top_n_elves(self.0.len()).into_iter().enumerate() {
+            let rank = match ranked.last() {
+                Some((prev_rank, prev_elf))
+                    if prev_elf.total_calories_carried() == elf.total_calories_carried() =>
+                {
+                    *prev_rank
+                }
+                _ => pos as u32 + 1,
+            };
+            ranked.push((rank, elf));
+        }
+        ranked
+    }
+
+    /// Saturates at `CalorieValue::MAX` instead of overflowing
+    pub fn top_n_elves_calories(&self, n: usize) -> CalorieValue {
+        self.top_n_elves(n).iter().fold(0u64, |acc, elf| {
+            acc.saturating_add(elf.total_calories_carried())
+        })
+    }
+
+    pub fn top_3_elves_calories(&self) -> CalorieValue {
+        self.top_n_elves_calories(3)
+    }
+
+    /// How many of the highest-calorie elves it takes to carry at least `budget` calories,
+    /// or `None` if the whole group falls short
+    pub fn elves_needed_for(&self, budget: CalorieValue) -> Option<usize> {
+        let mut calories: Vec<CalorieValue> = self.totals().map(|(_, total)| total).collect();
+        calories.sort_unstable_by_key(|cal| std::cmp::Reverse(*cal));
+
+        let mut carried: CalorieValue = 0;
+        for (needed, cal) in std::iter::once(0).chain(calories).enumerate() {
+            carried = carried.saturating_add(cal);
+            if carried >= budget {
+                return Some(needed);
+            }
+        }
+        None
+    }
+
+    pub fn calorie_statistics(&self) -> Option<CalorieStats> {
+        let mut calories: Vec<CalorieValue> = self.totals().map(|(_, total)| total).collect();
+        if calories.is_empty() {
+            return None;
+        }
+
+        calories.sort_unstable();
+
+        let len = calories.len();
+        // Wide enough for `u64::MAX` calories carried by each of up to `u64::MAX` elves
+        let exact_total: u128 = calories.iter().map(|cal| u128::from(*cal)).sum();
+        let total = CalorieValue::try_from(exact_total).unwrap_or(CalorieValue::MAX);
+        let median = if len.is_multiple_of(2) {
+            (calories[len / 2 - 1] as f64 + calories[len / 2] as f64) / 2.0
+        } else {
+            calories[len / 2] as f64
+        };
+
+        Some(CalorieStats {
+            min: calories[0],
+            max: calories[len - 1],
+            mean: exact_total as f64 / len as f64,
+            median,
+            total,
+        })
+    }
+
+    pub fn from_lines<I, S>(lines: I) -> Result<Self>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        Self::try_from_lines(lines.into_iter().map(Ok::<S, std::convert::Infallible>))
+    }
+
+    /// `(id, total_calories_carried)` of the elf carrying the most calories in `reader`, only ever
+    /// keeping track of the current elf's sum and the best one so far. Ties go to the last elf,
+    /// like `elf_with_most_calories`.
+    pub fn max_calories_streaming<R: std::io::BufRead>(reader: R) -> Result<(u64, CalorieValue)> {
+        let mut best: Option<(u64, CalorieValue)> = None;
+        let mut current: Option<CalorieValue> = None;
+        let mut id = 0;
+
+        let mut flush = |current: &mut Option<CalorieValue>| {
+            if let Some(total) = current.take() {
+                id += 1;
+                if best.is_none_or(|(_, best_total)| total >= best_total) {
+                    best = Some((id, total));
+                }
+            }
+        };
+
+        for (line_idx, line) in reader.lines().enumerate() {
+            let line = line?;
+            let value = line.trim();
+            if value.is_empty() {
+                flush(&mut current);
+                continue;
+            }
+            if comment(value).is_some() {
+                // A group made only of comments still stands for an elf, carrying nothing
+                current.get_or_insert(0);
+                continue;
+            }
+
+            let calorie_value = parse_calorie_value(line_idx + 1, value)?;
+            current = Some(current.unwrap_or(0).saturating_add(calorie_value));
+        }
+
+        // The last elf isn't followed by a blank line when the input has no trailing newline
+        flush(&mut current);
+
+        best.ok_or(Day1Error::NoElves)
+    }
+
+    /// Renders the group back into the puzzle input format, named elves being headed by their
+    /// `# name` comment. Unnamed elves carrying nothing are written as a lone `#`.
+    pub fn to_input_string(&self) -> String {
+        self.0
+            .iter()
+            .map(|elf| {
+                let name = match &elf.name {
+                    Some(name) => Some(format!("# {name}\n")),
+                    None if elf.food_carried.is_empty() => Some("#\n".to_owned()),
+                    None => None,
+                };
+                let food = elf
+                    .food_carried
+                    .iter()
+                    .map(|food| format!("{}\n", food.calorie_value));
+                name.into_iter().chain(food).collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// Lazily consumes `reader` line by line, without buffering the whole input first
+    pub fn read_from<R: std::io::BufRead>(reader: R) -> Result<Self> {
+        Self::try_from_lines(reader.lines())
+    }
+
+    fn try_from_lines<I, S, E>(lines: I) -> Result<Self>
+    where
+        I: IntoIterator<Item = std::result::Result<S, E>>,
+        S: AsRef<str>,
+        Day1Error: From<E>,
+    {
+        let mut elves = Self::default();
+
+        for group in aoc_core::groups(lines) {
+            let group = group?;
+            // Consecutive blank lines don't stand for an elf carrying nothing, unlike a group made
+            // only of comments
+            if group.is_empty() {
+                continue;
+            }
+            // Only a comment heading the group names the elf, others are ignored
+            let name = group
+                .first()
+                .and_then(|(_, line)| comment(line.as_ref()))
+                .map(str::trim)
+                .filter(|name| !name.is_empty())
+                .map(str::to_owned);
+            let food_carried = group
+                .into_iter()
+                .filter(|(_, line)| comment(line.as_ref()).is_none())
+                .map(|(line_no, line)| {
+                    let calorie_value = parse_calorie_value(line_no, line.as_ref())?;
+                    Ok(Food::with_label(calorie_value, format!("line {line_no}")))
+                })
+                .collect::<Result<Vec<_>>>()?;
+            let id = elves.0.len() as u64 + 1;
+            elves.0.push(Elf {
+                name,
+                ..Elf::with_food(id, food_carried)
+            });
+        }
+
+        Ok(elves)
+    }
+}
+
+/// Elves are kept in order along with their own ids, unlike `ElfGroup::add_elf` numbering them
+impl FromIterator<Elf> for ElfGroup {
+    fn from_iter<I: IntoIterator<Item = Elf>>(elves: I) -> Self {
+        Self(elves.into_iter().collect())
+    }
+}
+
+impl<'a> IntoIterator for &'a ElfGroup {
+    type Item = &'a Elf;
+    type IntoIter = std::slice::Iter<'a, Elf>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Elves by position in the group, which doesn't have to match their id
+impl std::ops::Index<usize> for ElfGroup {
+    type Output = Elf;
+
+    fn index(&self, idx: usize) -> &Elf {
+        &self.0[idx]
+    }
+}
+
+impl std::str::FromStr for ElfGroup {
+    type Err = Day1Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Self::from_lines(s.lines())
+    }
+}
+
+/// Day 1 puzzle: the chad elf's calories, then the top 3 elves' sum of calories
+#[cfg(feature = "eyre")]
+#[derive(Debug)]
+pub struct Day1;
+
+#[cfg(feature = "eyre")]
+impl aoc_core::Solution for Day1 {
+    type Input = ElfGroup;
+
+    #[cfg_attr(
+        feature = "trace",
+        tracing::instrument(level = "debug", skip_all, err, fields(lines = input.lines().count()))
+    )]
+    fn parse(input: &str) -> color_eyre::eyre::Result<Self::Input> {
+        Ok(input.parse()?)
+    }
+
+    #[cfg_attr(
+        feature = "trace",
+        tracing::instrument(level = "debug", skip_all, ret, err)
+    )]
+    fn part1(elves: &Self::Input) -> color_eyre::eyre::Result<String> {
+        let chad_elf = elves.elf_with_most_calories().ok_or(Day1Error::NoElves)?;
+        Ok(chad_elf.try_total_calories_carried()?.to_string())
+    }
+
+    #[cfg_attr(
+        feature = "trace",
+        tracing::instrument(level = "debug", skip_all, ret, err)
+    )]
+    fn part2(elves: &Self::Input) -> color_eyre::eyre::Result<String> {
+        Ok(elves.top_3_elves_calories().to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn conforms_to_brief_step1() {
+        let mut elves = ElfGroup::default();
+        elves
+            .add_elf(&[1000, 2000, 3000])
+            .add_elf(&[4000])
+            .add_elf(&[5000, 6000])
+            .add_elf(&[7000, 8000, 9000])
+            .add_elf(&[10000]);
+
+        let chad_elf = elves.elf_with_most_calories().unwrap();
+        assert_eq!(chad_elf.id, 4);
+        assert_eq!(chad_elf.total_calories_carried(), 24000);
+    }
+
+    #[test]
+    fn conforms_to_brief_step2() {
+        let mut elves = ElfGroup::default();
+        elves
+            .add_elf(&[1000, 2000, 3000])
+            .add_elf(&[4000])
+            .add_elf(&[5000, 6000])
+            .add_elf(&[7000, 8000, 9000])
+            .add_elf(&[10000]);
+
+        assert_eq!(elves.top_3_elves_calories(), 45000);
+    }
+
+    fn brief_elf_group() -> ElfGroup {
+        let mut elves = ElfGroup::default();
+        elves
+            .add_elf(&[1000, 2000, 3000])
+            .add_elf(&[4000])
+            .add_elf(&[5000, 6000])
+            .add_elf(&[7000, 8000, 9000])
+            .add_elf(&[10000]);
+        elves
+    }
+
+    const BRIEF_INPUT: &str = "1000
+2000
+3000
+
+4000
+
+5000
+6000
+
+7000
+8000
+9000
+
+10000
+";
+
+    #[test]
+    fn read_from_bufread() {
+        let reader = std::io::Cursor::new(BRIEF_INPUT.trim_end().as_bytes());
+        let elves = ElfGroup::read_from(reader).unwrap();
+        assert_eq!(elves.0.len(), 5);
+        assert_eq!(elves.0.last().unwrap().total_calories_carried(), 10000);
+        assert_eq!(elves.elf_with_most_calories().unwrap().id, 4);
+    }
+
+    #[test]
+    fn parsed_food_is_labeled_with_its_source_line() {
+        let elves: ElfGroup = "1000\n2000".parse().unwrap();
+        let labels: Vec<Option<&str>> = elves.0[0]
+            .food_carried
+            .iter()
+            .map(|food| food.label.as_deref())
+            .collect();
+        assert_eq!(labels, vec![Some("line 1"), Some("line 2")]);
+
+        assert!(Food::new(1000).label.is_none());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_json_roundtrip() {
+        let elves = brief_elf_group();
+        let json = serde_json::to_string(&elves).unwrap();
+        assert!(json.starts_with(r#"[{"id":1,"food_carried":[1000,2000,3000]}"#));
+
+        let roundtripped: ElfGroup = serde_json::from_str(&json).unwrap();
+        let chad_elf = roundtripped.elf_with_most_calories().unwrap();
+        assert_eq!(chad_elf.id, 4);
+        assert_eq!(chad_elf.total_calories_carried(), 24000);
+        assert_eq!(
+            roundtripped.0.iter().map(|elf| elf.id).collect::<Vec<_>>(),
+            vec![1, 2, 3, 4, 5]
+        );
+    }
+
+    #[test]
+    fn comments_name_the_following_elf() {
+        let input =
+            "# Bob\n1000\n2000\n\n3000\n# not a name\n4000\n\n  #  Alice  \n5000\n\n# Nobody\n";
+        let elves: ElfGroup = input.parse().unwrap();
+        assert_eq!(
+            elves
+                .iter()
+                .map(|elf| (elf.id, elf.name.as_deref(), elf.total_calories_carried()))
+                .collect::<Vec<_>>(),
+            [
+                (1, Some("Bob"), 3000),
+                (2, None, 7000),
+                (3, Some("Alice"), 5000),
+                (4, Some("Nobody"), 0)
+            ]
+        );
+        // Food lines keep their own numbers
+        assert_eq!(elves[1].food_carried[1].label.as_deref(), Some("line 7"));
+
+        assert_eq!(
+            ElfGroup::max_calories_streaming(std::io::Cursor::new(input)).unwrap(),
+            (2, 7000)
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_json_roundtrips_names() {
+        let elves: ElfGroup = "# Bob\n1000\n\n2000\n".parse().unwrap();
+        let json = serde_json::to_string(&elves).unwrap();
+        assert_eq!(
+            json,
+            r#"[{"id":1,"name":"Bob","food_carried":[1000]},{"id":2,"food_carried":[2000]}]"#
+        );
+
+        let roundtripped: ElfGroup = serde_json::from_str(&json).unwrap();
+        assert_eq!(roundtripped[0].name.as_deref(), Some("Bob"));
+        assert_eq!(roundtripped[1].name, None);
+    }
+
+    #[test]
+    fn to_input_string_roundtrip() {
+        let elves: ElfGroup = BRIEF_INPUT.parse().unwrap();
+        let input = elves.to_input_string();
+        assert_eq!(input, BRIEF_INPUT);
+
+        let roundtripped: ElfGroup = input.parse().unwrap();
+        assert_eq!(
+            roundtripped.totals().collect::<Vec<_>>(),
+            elves.totals().collect::<Vec<_>>()
+        );
+
+        let elves: ElfGroup = "# Bob\n1000\n\n2000\n".parse().unwrap();
+        assert_eq!(elves.to_input_string(), "# Bob\n1000\n\n2000\n");
+        assert_eq!(ElfGroup::default().to_input_string(), "");
+
+        // Elves carrying nothing survive the trip, named or not
+        let mut elves = ElfGroup::default();
+        elves.add_elf(&[1000]).add_elf(&[]).add_elf(&[2000]);
+        elves.0[2].name = Some("Bob".into());
+        elves.add_elf(&[]).0[3].name = Some("Nobody".into());
+        let input = elves.to_input_string();
+        assert_eq!(input, "1000\n\n#\n\n# Bob\n2000\n\n# Nobody\n");
+        let roundtripped: ElfGroup = input.parse().unwrap();
+        assert_eq!(roundtripped.len(), 4);
+        assert_eq!(roundtripped.to_input_string(), input);
+        assert_eq!(
+            ElfGroup::max_calories_streaming(std::io::Cursor::new(&input)).unwrap(),
+            (3, 2000)
+        );
+    }
+
+    #[test]
+    fn rebalance_plan() {
+        let elves = brief_elf_group();
+        let plan = elves.rebalance_plan(11000);
+        assert_eq!(
+            plan,
+            vec![(1, 5000), (2, 7000), (3, 0), (4, -13000), (5, 1000)]
+        );
+        // 5 elves carrying 11000 each is exactly the 55000 calories carried overall
+        assert_eq!(plan.iter().map(|(_, delta)| delta).sum::<i64>(), 0);
+
+        let plan = elves.rebalance_plan(10000);
+        assert_eq!(plan.iter().map(|(_, delta)| delta).sum::<i64>(), -5000);
+    }
+
+    #[test]
+    fn max_calories_streaming_matches_full_parse() {
+        for input in [
+            BRIEF_INPUT,
+            BRIEF_INPUT.trim_end(),
+            "1000\n\n\n3000\n\n2000",
+        ] {
+            let elves: ElfGroup = input.parse().unwrap();
+            let chad_elf = elves.elf_with_most_calories().unwrap();
+            assert_eq!(
+                ElfGroup::max_calories_streaming(std::io::Cursor::new(input)).unwrap(),
+                (chad_elf.id, chad_elf.total_calories_carried())
+            );
+        }
+
+        assert!(matches!(
+            ElfGroup::max_calories_streaming(std::io::Cursor::new("\n\n")),
+            Err(Day1Error::NoElves)
+        ));
+        assert!(matches!(
+            ElfGroup::max_calories_streaming(std::io::Cursor::new("1000\nabc\n")),
+            Err(Day1Error::NonNumericCalorie { line: 2, .. })
+        ));
+    }
+
+    #[test]
+    fn from_str_parses_brief() {
+        let elves: ElfGroup = BRIEF_INPUT.parse().unwrap();
+        assert_eq!(elves.0.len(), 5);
+        assert_eq!(elves.elf_with_most_calories().unwrap().id, 4);
+        assert_eq!(elves.top_3_elves_calories(), 45000);
+    }
+
+    #[test]
+    fn core_answers_brief_with_its_own_errors() -> Result<()> {
+        let elves: ElfGroup = BRIEF_INPUT.parse()?;
+        let most = elves.elf_with_most_calories().ok_or(Day1Error::NoElves)?;
+        assert_eq!(most.try_total_calories_carried()?, 24000);
+        assert_eq!(elves.top_3_elves_calories(), 45000);
+        Ok(())
+    }
+
+    #[cfg(feature = "eyre")]
+    #[test]
+    fn solution_answers_brief() {
+        use aoc_core::Solution as _;
+
+        let elves = Day1::parse(BRIEF_INPUT).unwrap();
+        assert_eq!(Day1::part1(&elves).unwrap(), "24000");
+        assert_eq!(Day1::part2(&elves).unwrap(), "45000");
+    }
+
+    #[cfg(all(feature = "eyre", feature = "trace"))]
+    #[test]
+    #[tracing_test::traced_test]
+    fn solution_phases_emit_spans() {
+        use aoc_core::Solution as _;
+
+        let elves = Day1::parse(BRIEF_INPUT).unwrap();
+        Day1::part1(&elves).unwrap();
+        Day1::part2(&elves).unwrap();
+        assert!(logs_contain("part1: day1: return=\"24000\""));
+        assert!(logs_contain("part2: day1: return=\"45000\""));
+
+        assert!(Day1::parse("1000\nabc\n").is_err());
+        assert!(logs_contain("parse{lines=2}: day1: error="));
+    }
+
+    #[test]
+    fn from_str_tolerates_crlf_and_trailing_whitespace() {
+        let input = BRIEF_INPUT.replace('\n', "\r\n") + "  \r\n\r\n";
+        let elves: ElfGroup = input.parse().unwrap();
+        assert_eq!(elves.0.len(), 5);
+        assert_eq!(elves.elf_with_most_calories().unwrap().id, 4);
+    }
+
+    #[test]
+    fn read_from_tolerates_crlf_and_trailing_whitespace() {
+        let input = BRIEF_INPUT.replace('\n', " \r\n") + "  \r\n\r\n";
+        let elves = ElfGroup::read_from(std::io::Cursor::new(&input)).unwrap();
+        assert_eq!(elves.0.len(), 5);
+        assert_eq!(elves.elf_with_most_calories().unwrap().id, 4);
+        assert_eq!(
+            ElfGroup::max_calories_streaming(std::io::Cursor::new(&input)).unwrap(),
+            (4, 24000)
+        );
+
+        let err = ElfGroup::read_from(std::io::Cursor::new("1000 \r\n12x3\r\n")).unwrap_err();
+        assert!(matches!(
+            err,
+            Day1Error::NonNumericCalorie { line: 2, ref value, .. } if value == "12x3"
+        ));
+    }
+
+    #[test]
+    fn consecutive_blank_lines_make_no_phantom_elf() {
+        let elves: ElfGroup = "1000\n2000\n\n\n500\n\n\n\n4000\n".parse().unwrap();
+        assert_eq!(elves.0.len(), 3);
+        assert_eq!(elves.elf_with_least_calories().unwrap().id, 2);
+        assert!(elves.totals().all(|(_, total)| total > 0));
+
+        let mut elves = ElfGroup::default();
+        assert!(matches!(elves.try_add_elf(&[]), Err(Day1Error::EmptyElf)));
+        elves.try_add_elf(&[1000]).unwrap();
+        assert_eq!(elves.0.len(), 1);
+    }
+
+    #[test]
+    fn from_str_rejects_non_numeric_lines() {
+        let err = "1000\nabc\n\n2000".parse::<ElfGroup>().unwrap_err();
+        assert!(matches!(
+            err,
+            Day1Error::NonNumericCalorie { line: 2, ref value, .. } if value == "abc"
+        ));
+    }
+
+    #[test]
+    fn non_numeric_calories_report_their_line() {
+        let input = "1000\n2000\n\n3000\n\n4000\n12x3\n5000\n";
+        let expected_message = "Invalid calorie value `12x3` on line 7";
+
+        let err = input.parse::<ElfGroup>().unwrap_err();
+        assert!(matches!(
+            err,
+            Day1Error::NonNumericCalorie { line: 7, ref value, .. } if value == "12x3"
+        ));
+        assert_eq!(err.to_string(), expected_message);
+
+        let err = ElfGroup::max_calories_streaming(std::io::Cursor::new(input)).unwrap_err();
+        assert_eq!(err.to_string(), expected_message);
+    }
+
+    #[test]
+    fn top_n_elves_calories_does_not_overflow() {
+        let mut elves = ElfGroup::default();
+        elves
+            .add_elf(&[u64::MAX])
+            .add_elf(&[u64::MAX])
+            .add_elf(&[1]);
+        assert_eq!(elves.top_3_elves_calories(), u64::MAX);
+        assert_eq!(elves.top_n_elves_calories(1), u64::MAX);
+    }
+
+    #[test]
+    fn total_calories_carried_does_not_wrap() {
+        let elf = Elf::new(7, &[u64::MAX - 1, 1]);
+        assert_eq!(elf.try_total_calories_carried().unwrap(), u64::MAX);
+        assert_eq!(elf.total_calories_carried(), u64::MAX);
+
+        let elf = Elf::new(7, &[u64::MAX - 1, 2]);
+        let err = elf.try_total_calories_carried().unwrap_err();
+        assert!(matches!(err, Day1Error::CalorieOverflow { id: 7, .. }));
+        assert!(err.to_string().contains("#7"));
+        assert_eq!(elf.total_calories_carried(), u64::MAX);
+    }
+
+    #[test]
+    fn ranked_uses_competition_ranking() {
+        let mut elves = ElfGroup::default();
+        elves
+            .add_elf(&[500])
+            .add_elf(&[900])
+            .add_elf(&[900])
+            .add_elf(&[100])
+            .add_elf(&[500]);
+
+        let ranking: Vec<(u32, u64)> = elves
+            .ranked()
+            .into_iter()
+            .map(|(rank, elf)| (rank, elf.id))
+            .collect();
+        assert_eq!(ranking, vec![(1, 2), (1, 3), (3, 1), (3, 5), (5, 4)]);
+    }
+
+    #[test]
+    fn elf_with_least_calories() {
+        let elves = brief_elf_group();
+        let slacker_elf = elves.elf_with_least_calories().unwrap();
+        assert_eq!(slacker_elf.id, 2);
+        assert_eq!(slacker_elf.total_calories_carried(), 4000);
+
+        let mut elves = ElfGroup::default();
+        elves.add_elf(&[300]).add_elf(&[100]).add_elf(&[100]);
+        assert_eq!(elves.elf_with_least_calories().unwrap().id, 2);
+
+        assert!(ElfGroup::default().elf_with_least_calories().is_none());
+    }
+
+    #[test]
+    fn calorie_statistics() {
+        let stats = brief_elf_group().calorie_statistics().unwrap();
+        assert_eq!(
+            stats,
+            CalorieStats {
+                min: 4000,
+                max: 24000,
+                mean: 11000.0,
+                median: 10000.0,
+                total: 55000,
+            }
+        );
+
+        let mut elves = ElfGroup::default();
+        elves.add_elf(&[1000]).add_elf(&[2000]);
+        assert_eq!(elves.calorie_statistics().unwrap().median, 1500.0);
+
+        assert!(ElfGroup::default().calorie_statistics().is_none());
+
+        let mut elves = ElfGroup::default();
+        elves.add_elf(&[u64::MAX]).add_elf(&[u64::MAX - 2]);
+        let stats = elves.calorie_statistics().unwrap();
+        assert_eq!(stats.total, u64::MAX);
+        assert_eq!(stats.mean, (u64::MAX - 1) as f64);
+    }
+
+    #[test]
+    fn totals() {
+        let totals: std::collections::HashMap<u64, CalorieValue> =
+            brief_elf_group().totals().collect();
+        assert_eq!(totals.len(), 5);
+        assert_eq!(totals[&4], 24000);
+        assert_eq!(totals[&2], 4000);
+    }
+
+    #[test]
+    fn top_n_elves_calories() {
+        let elves = brief_elf_group();
+        assert_eq!(elves.top_n_elves_calories(0), 0);
+        assert_eq!(elves.top_n_elves_calories(1), 24000);
+        assert_eq!(elves.top_n_elves_calories(3), 45000);
+        assert_eq!(elves.top_n_elves_calories(100), 55000);
+    }
+
+    #[test]
+    fn elf_group_from_iterator() {
+        let elves: ElfGroup = [Elf::new(3, &[1000]), Elf::new(1, &[2000]), Elf::new(2, &[])]
+            .into_iter()
+            .collect();
+        assert_eq!(elves.len(), 3);
+        assert!(!elves.is_empty());
+        assert_eq!(
+            elves.iter().map(|elf| elf.id).collect::<Vec<_>>(),
+            [3, 1, 2]
+        );
+        assert_eq!(elves[1].total_calories_carried(), 2000);
+
+        // Elves added afterwards are still numbered after the group's size
+        let mut elves = elves;
+        elves.add_elf(&[500]);
+        assert_eq!(elves[3].id, 4);
+        assert_eq!((&elves).into_iter().count(), 4);
+        assert!(ElfGroup::default().is_empty());
+    }
+
+    #[test]
+    fn partition_by_threshold() {
+        let elves: ElfGroup = BRIEF_INPUT.parse().unwrap();
+        let ids = |elves: Vec<&Elf>| elves.iter().map(|elf| elf.id).collect::<Vec<_>>();
+
+        let (heavy, light) = elves.partition_by_threshold(10000);
+        assert_eq!((heavy.len(), light.len()), (3, 2));
+        assert_eq!(ids(heavy), [3, 4, 5]);
+        assert_eq!(ids(light), [1, 2]);
+
+        let (heavy, light) = elves.partition_by_threshold(0);
+        assert_eq!((heavy.len(), light.len()), (5, 0));
+    }
+
+    #[test]
+    fn merge_renumbers_ids() {
+        let mut elves: ElfGroup = "1000\n2000\n\n3000\n".parse().unwrap();
+        let other: ElfGroup = "4000\n\n5000\n6000\n".parse().unwrap();
+        elves.merge(other);
+
+        assert_eq!(
+            elves.iter().map(|elf| elf.id).collect::<Vec<_>>(),
+            [1, 2, 3, 4]
+        );
+        assert_eq!(elves[2].total_calories_carried(), 4000);
+        assert_eq!(elves[3].total_calories_carried(), 11000);
+    }
+
+    #[test]
+    fn elves_needed_for() {
+        let elves: ElfGroup = BRIEF_INPUT.parse().unwrap();
+        // 24000 + 11000
+        assert_eq!(elves.elves_needed_for(35000), Some(2));
+        assert_eq!(elves.elves_needed_for(40000), Some(3));
+        assert_eq!(elves.elves_needed_for(24000), Some(1));
+        assert_eq!(elves.elves_needed_for(0), Some(0));
+        assert_eq!(elves.elves_needed_for(55000), Some(5));
+        assert_eq!(elves.elves_needed_for(55001), None);
+    }
+
+    #[test]
+    fn top_n_elves() {
+        let elves = brief_elf_group();
+        let top_3 = elves.top_n_elves(3);
+
+        assert_eq!(
+            top_3.iter().map(|elf| elf.id).collect::<Vec<_>>(),
+            vec![4, 3, 5]
+        );
+        assert_eq!(
+            top_3
+                .iter()
+                .map(|elf| elf.total_calories_carried())
+                .sum::<CalorieValue>(),
+            45000
+        );
+    }
+
+    #[test]
+    fn top_n_elves_ties_favor_lowest_id() {
+        let mut elves = ElfGroup::default();
+        elves.add_elf(&[100]).add_elf(&[300]).add_elf(&[300]);
+
+        let ids: Vec<u64> = elves.top_n_elves(3).iter().map(|elf| elf.id).collect();
+        assert_eq!(ids, vec![2, 3, 1]);
+    }
+
+    #[test]
+    fn from_lines_includes_last_elf_without_trailing_blank_line() {
+        let lines = [
+            "1000", "2000", "3000", "", "4000", "", "5000", "6000", "", "7000", "8000", "9000", "",
+            "10000",
+        ];
+
+        let elves = ElfGroup::from_lines(lines).unwrap();
+        assert_eq!(elves.0.len(), 5);
+
+        let last_elf = elves.0.last().unwrap();
+        assert_eq!(last_elf.id, 5);
+        assert_eq!(last_elf.total_calories_carried(), 10000);
+        assert_eq!(elves.elf_with_most_calories().unwrap().id, 4);
+    }
+}
@@ -1,104 +1,62 @@
-use color_eyre::eyre::{eyre, Result};
-
-type CalorieValue = u64;
-#[derive(Debug)]
-struct Food {
-    calorie_value: CalorieValue,
-}
-
-impl Food {
-    pub fn new(calorie_value: CalorieValue) -> Self {
-        Self { calorie_value }
-    }
-}
-
-#[derive(Debug)]
-struct Elf {
-    id: u64,
-    food_carried: Vec<Food>,
-}
-
-impl Elf {
-    pub fn new(id: u64, food_carried: &[CalorieValue]) -> Self {
-        Self {
-            id,
-            food_carried: food_carried
-                .into_iter()
-                .map(|calorie_value| Food::new(*calorie_value))
-                .collect(),
-        }
-    }
-
-    pub fn total_calories_carried(&self) -> CalorieValue {
-        self.food_carried
-            .iter()
-            .fold(0u64, |acc, food: &Food| acc + food.calorie_value)
-    }
-}
-
-#[derive(Debug, Default)]
-struct ElfGroup(Vec<Elf>);
-
-impl ElfGroup {
-    pub fn add_elf(&mut self, food_carried: &[CalorieValue]) -> &mut Self {
-        let new_id = self.0.len() + 1;
-        self.0.push(Elf::new(new_id as u64, food_carried));
-        self
-    }
-
-    pub fn elf_with_most_calories(&self) -> Option<&Elf> {
-        if self.0.is_empty() {
-            return None;
-        }
-
-        self.0.iter().max_by_key(|elf| elf.total_calories_carried())
-    }
-
-    pub fn top_3_elves_calories(&self) -> CalorieValue {
-        let mut calories: Vec<CalorieValue> =
-            self.0.iter().map(Elf::total_calories_carried).collect();
-        calories.sort_by_key(|cal| std::cmp::Reverse(*cal));
-        calories.into_iter().take(3).sum()
-    }
+use aoc_core::Solution as _;
+use clap::Parser;
+use color_eyre::eyre::Result;
+use day1::{Day1, ElfGroup};
+
+#[derive(Debug, Parser)]
+struct Args {
+    /// Elf list, `-` reading it from stdin. Defaults to the bundled one
+    input: Option<String>,
+    /// How many of the most-laden elves to sum the calories of
+    #[arg(long, default_value_t = 3, value_parser = clap::value_parser!(u64).range(1..))]
+    top: u64,
 }
 
 fn main() -> Result<()> {
+    #[cfg(feature = "trace")]
+    aoc_core::init_tracing();
     color_eyre::install()?;
 
-    use std::io::BufRead as _;
-
-    let file = std::fs::File::open("./src/elf_list.txt")?;
-    let lines = std::io::BufReader::new(file).lines();
-
-    let mut elves = ElfGroup::default();
-
-    let mut cur_food_carried: Vec<CalorieValue> = vec![];
+    let args = Args::parse();
+    let reader = match args.input.as_deref() {
+        Some("-") => aoc_core::input_reader(std::io::stdin().lock(), false),
+        path => aoc_core::open(
+            path.unwrap_or(concat!(env!("CARGO_MANIFEST_DIR"), "/src/elf_list.txt")),
+        )?,
+    };
+    // Validated by clap to be at least 1
+    let top = args.top as usize;
+    // Consumed line by line rather than read to a string first
+    let elves = ElfGroup::read_from(reader)?;
+
+    println!("Step1: Chad elf calories carried: {}", Day1::part1(&elves)?);
+    println!(
+        "Step2: Top {top} elves sum of calories: {}",
+        elves.top_n_elves_calories(top)
+    );
 
-    for line in lines {
-        if let Ok(calorie_value) = line {
-            if calorie_value.is_empty() {
-                elves.add_elf(cur_food_carried.as_slice());
-                cur_food_carried.clear();
-            } else {
-                cur_food_carried.push(calorie_value.parse()?);
-            }
-        }
+    if let Some(slacker_elf) = elves.elf_with_least_calories() {
+        println!(
+            "Slacker elf is elf #{} with {} calories carried",
+            slacker_elf.id,
+            slacker_elf.total_calories_carried()
+        );
     }
 
-    let chad_elf = elves
-        .elf_with_most_calories()
-        .ok_or_else(|| eyre!("Elves list is empty!"))?;
-
-    println!(
-        "Chad elf is elf #{} with {} calories carried",
-        chad_elf.id,
-        chad_elf.total_calories_carried()
-    );
+    for (rank, elf) in elves.ranked().into_iter().take(top) {
+        println!(
+            "#{rank}: elf #{} with {} calories carried",
+            elf.id,
+            elf.total_calories_carried()
+        );
+    }
 
-    println!(
-        "Top 3 elves sum of calories: {}",
-        elves.top_3_elves_calories()
-    );
+    if let Some(stats) = elves.calorie_statistics() {
+        println!(
+            "Calories per elf: min {} / max {} / mean {:.2} / median {} / total {}",
+            stats.min, stats.max, stats.mean, stats.median, stats.total
+        );
+    }
 
     Ok(())
 }
@@ -108,30 +66,16 @@ mod tests {
     use super::*;
 
     #[test]
-    fn conforms_to_brief_step1() {
-        let mut elves = ElfGroup::default();
-        elves
-            .add_elf(&[1000, 2000, 3000])
-            .add_elf(&[4000])
-            .add_elf(&[5000, 6000])
-            .add_elf(&[7000, 8000, 9000])
-            .add_elf(&[10000]);
-
-        let chad_elf = elves.elf_with_most_calories().unwrap();
-        assert_eq!(chad_elf.id, 4);
-        assert_eq!(chad_elf.total_calories_carried(), 24000);
-    }
+    fn parses_top_flag() {
+        let args = Args::try_parse_from(["day1"]).unwrap();
+        assert_eq!(args.top, 3);
+        assert_eq!(args.input, None);
 
-    #[test]
-    fn conforms_to_brief_step2() {
-        let mut elves = ElfGroup::default();
-        elves
-            .add_elf(&[1000, 2000, 3000])
-            .add_elf(&[4000])
-            .add_elf(&[5000, 6000])
-            .add_elf(&[7000, 8000, 9000])
-            .add_elf(&[10000]);
+        let args = Args::try_parse_from(["day1", "--top", "5", "-"]).unwrap();
+        assert_eq!(args.top, 5);
+        assert_eq!(args.input.as_deref(), Some("-"));
 
-        assert_eq!(elves.top_3_elves_calories(), 45000);
+        assert!(Args::try_parse_from(["day1", "--top", "0"]).is_err());
+        assert!(Args::try_parse_from(["day1", "--top", "many"]).is_err());
     }
 }
@@ -0,0 +1,23 @@
+use aoc_core::Solution;
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use day10::Day10;
+
+/// The brief program runs for the whole 240 cycles the real ones do
+const FIXTURE: &str = include_str!("../src/brief_program.txt");
+
+fn solution(c: &mut Criterion) {
+    let input = Day10::parse(FIXTURE).unwrap();
+
+    c.bench_function("day10 parse", |b| {
+        b.iter(|| Day10::parse(black_box(FIXTURE)))
+    });
+    c.bench_function("day10 part1", |b| {
+        b.iter(|| Day10::part1(black_box(&input)))
+    });
+    c.bench_function("day10 part2", |b| {
+        b.iter(|| Day10::part2(black_box(&input)))
+    });
+}
+
+criterion_group!(benches, solution);
+criterion_main!(benches);
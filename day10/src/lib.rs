@@ -0,0 +1,270 @@
+#[derive(Debug, thiserror::Error)]
+pub enum Day10Error {
+    #[error("Unknown instruction `{value}` on line {line}")]
+    UnknownInstruction { line: usize, value: String },
+    #[error("Invalid operand `{value}` on line {line}")]
+    InvalidOperand {
+        line: usize,
+        value: String,
+        #[source]
+        source: std::num::ParseIntError,
+    },
+    #[error("X register overflowed adding {operand} on instruction {instruction}")]
+    RegisterOverflow { instruction: usize, operand: i32 },
+}
+
+pub type Result<T, E = Day10Error> = std::result::Result<T, E>;
+
+/// Width of the CRT, in pixels
+pub const CRT_WIDTH: usize = 40;
+/// Height of the CRT, in pixels
+pub const CRT_HEIGHT: usize = 6;
+/// Cycles during which the signal strength is sampled
+pub const SAMPLED_CYCLES: [usize; 6] = [20, 60, 100, 140, 180, 220];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Instruction {
+    Noop,
+    Addx(i32),
+}
+
+impl Instruction {
+    /// Cycles the instruction takes to complete
+    pub fn cycles(&self) -> usize {
+        match self {
+            Self::Noop => 1,
+            Self::Addx(_) => 2,
+        }
+    }
+
+    fn parse(line: usize, value: &str) -> Result<Self> {
+        match value.split_whitespace().collect::<Vec<_>>()[..] {
+            ["noop"] => Ok(Self::Noop),
+            ["addx", operand] => {
+                operand
+                    .parse()
+                    .map(Self::Addx)
+                    .map_err(|source| Day10Error::InvalidOperand {
+                        line,
+                        value: operand.to_owned(),
+                        source,
+                    })
+            }
+            _ => Err(Day10Error::UnknownInstruction {
+                line,
+                value: value.to_owned(),
+            }),
+        }
+    }
+}
+
+/// Value of the X register during each cycle of a program
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RegisterTrace {
+    during: Vec<i32>,
+    /// Left in the register once the program is done
+    final_value: i32,
+}
+
+impl RegisterTrace {
+    /// Cycles the program took to complete
+    pub fn cycles(&self) -> usize {
+        self.during.len()
+    }
+
+    /// Register value during the 1-based `cycle`, the register keeping its value once the
+    /// program is done
+    pub fn during(&self, cycle: usize) -> i32 {
+        cycle
+            .checked_sub(1)
+            .and_then(|idx| self.during.get(idx))
+            .copied()
+            .unwrap_or(self.final_value)
+    }
+
+    /// Cycle number times the register value, during `cycle`
+    pub fn signal_strength(&self, cycle: usize) -> i64 {
+        cycle as i64 * i64::from(self.during(cycle))
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Program(pub Vec<Instruction>);
+
+impl Program {
+    /// Runs the program, failing if an `addx` takes the register out of `i32`'s range
+    pub fn trace(&self) -> Result<RegisterTrace> {
+        let mut x: i32 = 1;
+        let mut during = Vec::with_capacity(self.0.iter().map(Instruction::cycles).sum());
+        for (idx, instruction) in self.0.iter().enumerate() {
+            during.extend(std::iter::repeat_n(x, instruction.cycles()));
+            if let &Instruction::Addx(operand) = instruction {
+                x = x.checked_add(operand).ok_or(Day10Error::RegisterOverflow {
+                    instruction: idx + 1,
+                    operand,
+                })?;
+            }
+        }
+        Ok(RegisterTrace {
+            during,
+            final_value: x,
+        })
+    }
+
+    /// Sum of the signal strengths during the sampled cycles
+    pub fn signal_strength_sum(&self) -> Result<i64> {
+        let trace = self.trace()?;
+        Ok(SAMPLED_CYCLES
+            .into_iter()
+            .map(|cycle| trace.signal_strength(cycle))
+            .sum())
+    }
+
+    /// Draws the CRT, one line per row, lit pixels being `#` and dark ones `.`
+    ///
+    /// Pixels are lit when the 3 pixels wide sprite, centered on the register value, covers
+    /// them as they're drawn.
+    pub fn render(&self) -> Result<String> {
+        let trace = self.trace()?;
+        Ok((0..CRT_HEIGHT)
+            .map(|row| {
+                (0..CRT_WIDTH)
+                    .map(|col| {
+                        let sprite = trace.during(row * CRT_WIDTH + col + 1);
+                        if (i64::from(sprite) - col as i64).abs() <= 1 {
+                            '#'
+                        } else {
+                            '.'
+                        }
+                    })
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n"))
+    }
+}
+
+impl std::str::FromStr for Program {
+    type Err = Day10Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        s.lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty())
+            .map(|(idx, line)| Instruction::parse(idx + 1, line))
+            .collect::<Result<Vec<_>>>()
+            .map(Self)
+    }
+}
+
+/// Day 10 puzzle: sum of the sampled signal strengths, then the letters drawn on the CRT
+#[cfg(feature = "eyre")]
+#[derive(Debug)]
+pub struct Day10;
+
+#[cfg(feature = "eyre")]
+impl aoc_core::Solution for Day10 {
+    type Input = Program;
+
+    #[cfg_attr(
+        feature = "trace",
+        tracing::instrument(level = "debug", skip_all, err, fields(lines = input.lines().count()))
+    )]
+    fn parse(input: &str) -> color_eyre::eyre::Result<Self::Input> {
+        Ok(input.parse()?)
+    }
+
+    #[cfg_attr(
+        feature = "trace",
+        tracing::instrument(level = "debug", skip_all, ret, err)
+    )]
+    fn part1(program: &Self::Input) -> color_eyre::eyre::Result<String> {
+        Ok(program.signal_strength_sum()?.to_string())
+    }
+
+    #[cfg_attr(
+        feature = "trace",
+        tracing::instrument(level = "debug", skip_all, ret, err)
+    )]
+    fn part2(program: &Self::Input) -> color_eyre::eyre::Result<String> {
+        // The CRT starts on its own line, below the part's label
+        Ok(format!("\n{}", program.render()?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BRIEF_INPUT: &str = include_str!("brief_program.txt");
+
+    #[test]
+    fn traces_small_program() {
+        let program: Program = "noop\naddx 3\naddx -5\n".parse().unwrap();
+        let trace = program.trace().unwrap();
+        assert_eq!(trace.cycles(), 5);
+        assert_eq!(
+            (1..=6).map(|c| trace.during(c)).collect::<Vec<_>>(),
+            [1, 1, 1, 4, 4, -1]
+        );
+        // Past the end of the program
+        assert_eq!(trace.during(100), -1);
+    }
+
+    #[test]
+    fn conforms_to_brief_step1() {
+        let program: Program = BRIEF_INPUT.parse().unwrap();
+        let trace = program.trace().unwrap();
+        assert_eq!(trace.cycles(), 240);
+        assert_eq!(
+            SAMPLED_CYCLES.map(|cycle| trace.signal_strength(cycle)),
+            [420, 1140, 1800, 2940, 2880, 3960]
+        );
+        assert_eq!(program.signal_strength_sum().unwrap(), 13140);
+    }
+
+    #[test]
+    fn conforms_to_brief_step2() {
+        let program: Program = BRIEF_INPUT.parse().unwrap();
+        assert_eq!(
+            program.render().unwrap(),
+            "##..##..##..##..##..##..##..##..##..##..
+###...###...###...###...###...###...###.
+####....####....####....####....####....
+#####.....#####.....#####.....#####.....
+######......######......######......####
+#######.......#######.......#######....."
+        );
+    }
+
+    #[test]
+    fn rejects_malformed_instructions() {
+        assert!(matches!(
+            "noop\njmp 2".parse::<Program>(),
+            Err(Day10Error::UnknownInstruction { line: 2, .. })
+        ));
+        assert!(matches!(
+            "addx".parse::<Program>(),
+            Err(Day10Error::UnknownInstruction { line: 1, .. })
+        ));
+        assert!(matches!(
+            "noop\n\naddx two".parse::<Program>(),
+            Err(Day10Error::InvalidOperand { line: 3, .. })
+        ));
+    }
+
+    #[test]
+    fn rejects_register_overflow() {
+        let program: Program = "addx 2147483647\naddx 1".parse().unwrap();
+        assert!(matches!(
+            program.trace(),
+            // X starts at 1, so the first `addx` already overflows
+            Err(Day10Error::RegisterOverflow {
+                instruction: 1,
+                operand: i32::MAX
+            })
+        ));
+        assert!(program.signal_strength_sum().is_err());
+        assert!(program.render().is_err());
+    }
+}
@@ -0,0 +1,24 @@
+use aoc_core::Solution as _;
+use color_eyre::eyre::Result;
+use day10::Day10;
+
+fn main() -> Result<()> {
+    #[cfg(feature = "trace")]
+    aoc_core::init_tracing();
+    color_eyre::install()?;
+
+    // Reads the puzzle input from the given path, or from stdin
+    let input = match std::env::args().nth(1) {
+        Some(path) if path != "-" => aoc_core::read_to_string(path)?,
+        _ => std::io::read_to_string(std::io::stdin().lock())?,
+    };
+    let program = Day10::parse(&input)?;
+
+    println!(
+        "Step1: Sum of the sampled signal strengths: {}",
+        Day10::part1(&program)?
+    );
+    println!("Step2: CRT image: {}", Day10::part2(&program)?);
+
+    Ok(())
+}
@@ -0,0 +1,20 @@
+use aoc_core::Solution;
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use day2::Day2;
+
+/// Brief example repeated, so that the benches don't need the real puzzle input
+const FIXTURE: &str = include_str!("fixture.txt");
+
+fn solution(c: &mut Criterion) {
+    let input = Day2::parse(FIXTURE).unwrap();
+
+    c.bench_function("day2 parse", |b| b.iter(|| Day2::parse(black_box(FIXTURE))));
+    c.bench_function("day2 parse ascii", |b| {
+        b.iter(|| day2::StrategyGuide::from_ascii(black_box(FIXTURE.as_bytes())))
+    });
+    c.bench_function("day2 part1", |b| b.iter(|| Day2::part1(black_box(&input))));
+    c.bench_function("day2 part2", |b| b.iter(|| Day2::part2(black_box(&input))));
+}
+
+criterion_group!(benches, solution);
+criterion_main!(benches);
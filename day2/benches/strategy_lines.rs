@@ -0,0 +1,51 @@
+//! Parses a large guide line by line, to keep an eye on the per-line cost of splitting columns.
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion, Throughput};
+use day2::{random_choice, StrategyLine};
+use rand::SeedableRng as _;
+
+const LINES: usize = 100_000;
+
+/// Random guide lines, always the same ones so that runs can be compared
+fn synthetic_guide() -> String {
+    let mut rng = rand::rngs::StdRng::seed_from_u64(2022);
+    let mut guide = String::with_capacity(LINES * 4);
+    for _ in 0..LINES {
+        let opponent = random_choice(&mut rng).points() as u8 - 1;
+        let me = random_choice(&mut rng).points() as u8 - 1;
+        guide.push(char::from(b'A' + opponent));
+        guide.push(' ');
+        guide.push(char::from(b'X' + me));
+        guide.push('\n');
+    }
+    guide
+}
+
+fn strategy_lines(c: &mut Criterion) {
+    let guide = synthetic_guide();
+
+    let mut group = c.benchmark_group("strategy_lines");
+    group.throughput(Throughput::Elements(LINES as u64));
+    group.bench_function("from_str", |b| {
+        b.iter(|| {
+            black_box(&guide)
+                .lines()
+                .map(str::parse::<StrategyLine>)
+                .collect::<Result<Vec<_>, _>>()
+        })
+    });
+    group.bench_function("from_ascii", |b| {
+        b.iter(|| {
+            black_box(&guide)
+                .as_bytes()
+                .split(|b| *b == b'\n')
+                .filter(|line| !line.is_empty())
+                .map(StrategyLine::from_ascii)
+                .collect::<Result<Vec<_>, _>>()
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, strategy_lines);
+criterion_main!(benches);
@@ -0,0 +1,993 @@
+use crate::scoring::*;
+
+#[derive(Debug, thiserror::Error)]
+pub enum Day2Error {
+    #[error("Strategy line `{line}` should have exactly 2 columns, found {found}")]
+    WrongColumnCount { line: String, found: usize },
+    #[error("Invalid opponent choice `{value}` in `{line}`")]
+    InvalidOpponentChoice {
+        value: String,
+        line: String,
+        #[source]
+        source: strum::ParseError,
+    },
+    #[error("Invalid choice `{value}` in `{line}`")]
+    InvalidChoice {
+        value: String,
+        line: String,
+        #[source]
+        source: strum::ParseError,
+    },
+    #[error("Invalid desired outcome `{value}` in `{line}`")]
+    InvalidOutcome {
+        value: String,
+        line: String,
+        #[source]
+        source: strum::ParseError,
+    },
+    #[error("Cannot score {target} points in {rounds} rounds, reachable scores are {min}..={max}")]
+    UnreachableScore {
+        target: u64,
+        rounds: usize,
+        min: u64,
+        max: u64,
+    },
+    #[error("Strategy guide bytes are missing their length prefix")]
+    MissingLengthPrefix,
+    #[error("Strategy guide bytes announce {announced} fights but hold {actual} bytes of fights")]
+    LengthMismatch { announced: usize, actual: usize },
+    #[error("Invalid choice byte {0} in strategy guide bytes")]
+    InvalidChoiceByte(u8),
+    #[error("Unknown choice `{}`, expected one of A, B, C, X, Y or Z", char::from(*.0))]
+    UnknownChoiceLetter(u8),
+    #[error("Unknown desired outcome `{}`, expected one of X, Y or Z", char::from(*.0))]
+    UnknownOutcomeLetter(u8),
+    #[error("Column `{value}` in `{line}` should be a single letter")]
+    NotASingleLetter { value: String, line: String },
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+}
+
+pub type Result<T, E = Day2Error> = std::result::Result<T, E>;
+
+/// Plays a live game, reading my moves line by line from `input` against an opponent drawing its
+/// moves from `rng`, until `input` is exhausted or I quit with `q`
+pub fn play_interactive<R, W>(
+    input: R,
+    mut output: W,
+    rng: &mut impl rand::Rng,
+) -> Result<MatchResult>
+where
+    R: std::io::BufRead,
+    W: std::io::Write,
+{
+    let mut result = MatchResult::default();
+    let mut lines = input.lines();
+    let mut round = 1;
+
+    loop {
+        write!(output, "Round {round} - (R)ock, (P)aper or (S)cissors? ")?;
+        output.flush()?;
+
+        let Some(line) = lines.next() else {
+            writeln!(output)?;
+            break;
+        };
+
+        let me = match line?.trim().to_ascii_lowercase().as_str() {
+            "r" | "rock" => Choice::Rock,
+            "p" | "paper" => Choice::Paper,
+            "s" | "scissors" => Choice::Scissors,
+            "q" | "quit" => break,
+            other => {
+                writeln!(output, "Unknown move `{other}`, try again")?;
+                continue;
+            }
+        };
+
+        let opponent = random_choice(rng);
+        let round_result = ChoiceFight { opponent, me }.round_result();
+        result.me += round_result.me_points;
+        result.opponent += round_result.opponent_points;
+
+        writeln!(
+            output,
+            "You played {me}, opponent played {opponent}: {}! Score: me [{}] vs opponent [{}]",
+            round_result.outcome, result.me, result.opponent
+        )?;
+        round += 1;
+    }
+
+    Ok(result)
+}
+
+impl Choice {
+    /// Same as parsing a single letter column, without going through `strum`
+    pub fn from_byte(b: u8) -> Result<Self> {
+        match b {
+            b'A' | b'X' => Ok(Self::Rock),
+            b'B' | b'Y' => Ok(Self::Paper),
+            b'C' | b'Z' => Ok(Self::Scissors),
+            _ => Err(Day2Error::UnknownChoiceLetter(b)),
+        }
+    }
+
+    /// Letter standing for this choice in my column of the guide, found through `from_byte` so
+    /// that both always agree
+    fn my_column(&self) -> u8 {
+        Self::column(*self, b"XYZ")
+    }
+
+    /// Letter standing for this choice in the opponent's column of the guide
+    fn opponent_column(&self) -> u8 {
+        Self::column(*self, b"ABC")
+    }
+
+    fn column(choice: Self, letters: &[u8]) -> u8 {
+        letters
+            .iter()
+            .copied()
+            .find(|letter| Self::from_byte(*letter).ok() == Some(choice))
+            .expect("Every choice has a letter in each column")
+    }
+}
+
+impl ChoiceFightOutcome {
+    /// Same as parsing a single letter column, without going through `strum`
+    pub fn from_byte(b: u8) -> Result<Self> {
+        match b {
+            b'X' => Ok(Self::Loss),
+            b'Y' => Ok(Self::Draw),
+            b'Z' => Ok(Self::Win),
+            _ => Err(Day2Error::UnknownOutcomeLetter(b)),
+        }
+    }
+}
+
+/// A single line of the strategy guide, with its second column kept under both of its
+/// possible interpretations so that parsing one can never be mistaken for the other
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StrategyLine {
+    pub opponent: Choice,
+    pub as_choice: Choice,
+    pub as_outcome: ChoiceFightOutcome,
+}
+
+impl std::str::FromStr for StrategyLine {
+    type Err = Day2Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        // Columns can be separated by any run of whitespace and/or commas
+        let mut columns = s
+            .split(|c: char| c.is_whitespace() || c == ',')
+            .filter(|column| !column.is_empty());
+        // Matched in place rather than collected, sparing an allocation per line
+        let (first, second) = match (columns.next(), columns.next(), columns.next()) {
+            (Some(first), Some(second), None) => (first, second),
+            (first, second, third) => {
+                let found = [first, second, third].iter().flatten().count() + columns.count();
+                return Err(Day2Error::WrongColumnCount {
+                    line: s.to_owned(),
+                    found,
+                });
+            }
+        };
+
+        let opponent: Choice =
+            first
+                .parse()
+                .map_err(|source| Day2Error::InvalidOpponentChoice {
+                    value: first.to_owned(),
+                    line: s.to_owned(),
+                    source,
+                })?;
+        let as_choice: Choice = second.parse().map_err(|source| Day2Error::InvalidChoice {
+            value: second.to_owned(),
+            line: s.to_owned(),
+            source,
+        })?;
+        let as_outcome: ChoiceFightOutcome =
+            second.parse().map_err(|source| Day2Error::InvalidOutcome {
+                value: second.to_owned(),
+                line: s.to_owned(),
+                source,
+            })?;
+
+        Ok(Self {
+            opponent,
+            as_choice,
+            as_outcome,
+        })
+    }
+}
+
+impl StrategyLine {
+    /// Same as `from_str`, but matches on the raw ASCII bytes of `line` instead of
+    /// allocating and going through `strum`
+    pub fn from_ascii(line: &[u8]) -> Result<Self> {
+        let is_separator = |b: &u8| b.is_ascii_whitespace() || *b == b',';
+        let lossy = || String::from_utf8_lossy(line).into_owned();
+        let mut columns = line.split(is_separator).filter(|column| !column.is_empty());
+
+        let (first, second) = match (columns.next(), columns.next(), columns.next()) {
+            (Some(first), Some(second), None) => (first, second),
+            (first, second, third) => {
+                let found = [first, second, third].iter().flatten().count() + columns.count();
+                return Err(Day2Error::WrongColumnCount {
+                    line: lossy(),
+                    found,
+                });
+            }
+        };
+        let letter = |column: &[u8]| match column {
+            [b] => Ok(*b),
+            _ => Err(Day2Error::NotASingleLetter {
+                value: String::from_utf8_lossy(column).into_owned(),
+                line: lossy(),
+            }),
+        };
+
+        let opponent = Choice::from_byte(letter(first)?)?;
+        let second = letter(second)?;
+        Ok(Self {
+            opponent,
+            as_choice: Choice::from_byte(second)?,
+            as_outcome: ChoiceFightOutcome::from_byte(second)?,
+        })
+    }
+}
+
+#[derive(Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct StrategyGuide(pub Vec<ChoiceFight>);
+
+impl StrategyGuide {
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn iter(&self) -> std::slice::Iter<'_, ChoiceFight> {
+        self.0.iter()
+    }
+
+    fn strategy_lines(s: &str) -> impl Iterator<Item = Result<StrategyLine>> + '_ {
+        s.lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(str::parse::<StrategyLine>)
+    }
+
+    /// Parses the guide with its second column being the desired outcome, i.e. the step 2 reading
+    pub fn from_str_as_outcomes(s: &str) -> Result<Self> {
+        Self::strategy_lines(s)
+            .map(|line| {
+                let StrategyLine {
+                    opponent,
+                    as_outcome,
+                    ..
+                } = line?;
+                Ok(ChoiceFight {
+                    opponent,
+                    me: opponent.solve_outcome(&as_outcome),
+                })
+            })
+            .collect::<Result<Vec<ChoiceFight>>>()
+            .map(Self)
+    }
+
+    /// Same as `from_str`, i.e. the step 1 reading, but parsing the raw bytes of an ASCII guide
+    pub fn from_ascii(input: &[u8]) -> Result<Self> {
+        input
+            .split(|b| *b == b'\n')
+            .filter(|line| !line.trim_ascii().is_empty())
+            .map(|line| {
+                let StrategyLine {
+                    opponent,
+                    as_choice,
+                    ..
+                } = StrategyLine::from_ascii(line)?;
+                Ok(ChoiceFight {
+                    opponent,
+                    me: as_choice,
+                })
+            })
+            .collect::<Result<Vec<ChoiceFight>>>()
+            .map(Self)
+    }
+
+    /// Reinterprets a guide parsed with the step 1 reading under the step 2 one, my choice's
+    /// column standing for the desired outcome instead
+    pub fn as_outcome_guide(&self) -> Self {
+        Self(
+            self.0
+                .iter()
+                .map(|fight| {
+                    // My column's letter, read back as an outcome
+                    let desired_outcome = ChoiceFightOutcome::from_byte(fight.me.my_column())
+                        .expect("My column's letters all stand for an outcome");
+                    ChoiceFight {
+                        opponent: fight.opponent,
+                        me: fight.opponent.solve_outcome(&desired_outcome),
+                    }
+                })
+                .collect(),
+        )
+    }
+
+    /// Builds a guide of `rounds` fights in which I score exactly `target_me_score` points.
+    ///
+    /// A round is worth between 1 (losing with Rock) and 9 (winning with Scissors) points to me,
+    /// so the guide is built greedily by filling rounds with 9-point wins while leaving at least
+    /// a point for each remaining round, the last filled round taking whatever is left.
+    pub fn synthesize(target_me_score: u64, rounds: usize) -> Result<Self> {
+        const MIN_ROUND_SCORE: u64 = 1;
+        const MAX_ROUND_SCORE: u64 = 9;
+
+        let rounds_count = rounds as u64;
+        if target_me_score < MIN_ROUND_SCORE * rounds_count
+            || target_me_score > MAX_ROUND_SCORE * rounds_count
+        {
+            return Err(Day2Error::UnreachableScore {
+                target: target_me_score,
+                rounds,
+                min: MIN_ROUND_SCORE * rounds_count,
+                max: MAX_ROUND_SCORE * rounds_count,
+            });
+        }
+
+        let mut remaining = target_me_score;
+        let fights = (0..rounds_count)
+            .map(|round| {
+                let rounds_left_after = rounds_count - round - 1;
+                let round_score =
+                    (remaining - rounds_left_after * MIN_ROUND_SCORE).min(MAX_ROUND_SCORE);
+                remaining -= round_score;
+
+                // Round scores 1..=9 map to Rock/Paper/Scissors losses, then draws, then wins
+                let me = Classic.choices()[((round_score - 1) % 3) as usize];
+                let outcome = match (round_score - 1) / 3 {
+                    0 => ChoiceFightOutcome::Loss,
+                    1 => ChoiceFightOutcome::Draw,
+                    _ => ChoiceFightOutcome::Win,
+                };
+                let opponent = *Classic
+                    .choices()
+                    .iter()
+                    .find(|opponent| Classic.outcome(me, **opponent) == outcome)
+                    .expect("Every outcome is reachable in classic Rock-Paper-Scissors");
+
+                ChoiceFight { opponent, me }
+            })
+            .collect();
+
+        Ok(Self(fights))
+    }
+
+    /// Renders the guide back into `A Y` lines, my choice's column standing for my move as in
+    /// the step 1 reading
+    pub fn to_input_string(&self) -> String {
+        self.0
+            .iter()
+            .map(|fight| {
+                format!(
+                    "{} {}\n",
+                    char::from(fight.opponent.opponent_column()),
+                    char::from(fight.me.my_column())
+                )
+            })
+            .collect()
+    }
+
+    /// Compact binary encoding: the fight count as a little-endian `u32`, followed by two bytes
+    /// per fight holding the opponent's then my choice points
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(4 + self.0.len() * 2);
+        bytes.extend_from_slice(&(self.0.len() as u32).to_le_bytes());
+        for fight in &self.0 {
+            bytes.push(fight.opponent.points() as u8);
+            bytes.push(fight.me.points() as u8);
+        }
+        bytes
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        let (len, fights) = bytes
+            .split_first_chunk::<4>()
+            .ok_or(Day2Error::MissingLengthPrefix)?;
+        let len = u32::from_le_bytes(*len) as usize;
+        if fights.len() != len * 2 {
+            return Err(Day2Error::LengthMismatch {
+                announced: len,
+                actual: fights.len(),
+            });
+        }
+
+        let choice =
+            |byte: u8| Choice::from_points(byte as u64).ok_or(Day2Error::InvalidChoiceByte(byte));
+
+        fights
+            .chunks_exact(2)
+            .map(|fight| {
+                Ok(ChoiceFight {
+                    opponent: choice(fight[0])?,
+                    me: choice(fight[1])?,
+                })
+            })
+            .collect::<Result<Vec<ChoiceFight>>>()
+            .map(Self)
+    }
+
+    pub fn round_results(&self) -> Vec<RoundResult> {
+        self.iter().map(ChoiceFight::round_result).collect()
+    }
+
+    pub fn outcome_tally(&self) -> OutcomeTally {
+        self.0
+            .iter()
+            .fold(OutcomeTally::default(), |mut tally, fight| {
+                match fight.outcome() {
+                    ChoiceFightOutcome::Loss => tally.losses += 1,
+                    ChoiceFightOutcome::Draw => tally.draws += 1,
+                    ChoiceFightOutcome::Win => tally.wins += 1,
+                }
+                tally
+            })
+    }
+
+    /// How many times the opponent played each choice, in `Choice::all` order
+    pub fn opponent_move_frequency(&self) -> [(Choice, usize); 3] {
+        self.move_frequency(|fight| fight.opponent)
+    }
+
+    /// How many times I played each choice, in `Choice::all` order
+    pub fn my_move_frequency(&self) -> [(Choice, usize); 3] {
+        self.move_frequency(|fight| fight.me)
+    }
+
+    fn move_frequency(
+        &self,
+        player_choice: impl Fn(&ChoiceFight) -> Choice,
+    ) -> [(Choice, usize); 3] {
+        [Choice::Rock, Choice::Paper, Choice::Scissors].map(|choice| {
+            let count = self
+                .0
+                .iter()
+                .filter(|fight| player_choice(fight) == choice)
+                .count();
+            (choice, count)
+        })
+    }
+
+    pub fn points_scored(&self) -> MatchResult {
+        self.0
+            .iter()
+            .map(|fight| MatchResult {
+                me: fight.score_me(),
+                opponent: fight.score_opponent(),
+            })
+            .sum()
+    }
+
+    /// My score if I won every fight, only the opponent's column mattering. Winning is always
+    /// the best outcome, as the worst win (7 points) beats the best draw (6 points).
+    pub fn max_possible_score(&self) -> u64 {
+        self.0
+            .iter()
+            .map(|fight| {
+                ChoiceFight {
+                    opponent: fight.opponent,
+                    me: fight.opponent.solve_outcome(&ChoiceFightOutcome::Win),
+                }
+                .score_me()
+            })
+            .sum()
+    }
+}
+
+impl<'a> IntoIterator for &'a StrategyGuide {
+    type Item = &'a ChoiceFight;
+    type IntoIter = std::slice::Iter<'a, ChoiceFight>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl std::str::FromStr for StrategyGuide {
+    type Err = Day2Error;
+
+    /// Parses the guide with its second column being the move to play, i.e. the step 1 reading
+    fn from_str(s: &str) -> Result<Self> {
+        Self::strategy_lines(s)
+            .map(|line| {
+                let StrategyLine {
+                    opponent,
+                    as_choice,
+                    ..
+                } = line?;
+                Ok(ChoiceFight {
+                    opponent,
+                    me: as_choice,
+                })
+            })
+            .collect::<Result<Vec<ChoiceFight>>>()
+            .map(Self)
+    }
+}
+
+/// Day 2 puzzle: my score with the guide read as moves, then read as desired outcomes
+#[cfg(feature = "eyre")]
+#[derive(Debug)]
+pub struct Day2;
+
+#[cfg(feature = "eyre")]
+impl aoc_core::Solution for Day2 {
+    /// The guide under its step 1 and step 2 readings
+    type Input = (StrategyGuide, StrategyGuide);
+
+    #[cfg_attr(
+        feature = "trace",
+        tracing::instrument(level = "debug", skip_all, err, fields(lines = input.lines().count()))
+    )]
+    fn parse(input: &str) -> color_eyre::eyre::Result<Self::Input> {
+        let guide = StrategyGuide::from_ascii(input.as_bytes())?;
+        let outcome_guide = guide.as_outcome_guide();
+        Ok((guide, outcome_guide))
+    }
+
+    #[cfg_attr(
+        feature = "trace",
+        tracing::instrument(level = "debug", skip_all, ret, err)
+    )]
+    fn part1((guide, _): &Self::Input) -> color_eyre::eyre::Result<String> {
+        Ok(guide.points_scored().me.to_string())
+    }
+
+    #[cfg_attr(
+        feature = "trace",
+        tracing::instrument(level = "debug", skip_all, ret, err)
+    )]
+    fn part2((_, guide): &Self::Input) -> color_eyre::eyre::Result<String> {
+        Ok(guide.points_scored().me.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn conforms_to_brief_step1() {
+        let strategy_guide = StrategyGuide(vec![
+            ChoiceFight {
+                opponent: Choice::from_str("A").unwrap(),
+                me: Choice::from_str("Y").unwrap(),
+            },
+            ChoiceFight {
+                opponent: Choice::from_str("B").unwrap(),
+                me: Choice::from_str("X").unwrap(),
+            },
+            ChoiceFight {
+                opponent: Choice::from_str("C").unwrap(),
+                me: Choice::from_str("Z").unwrap(),
+            },
+        ]);
+
+        assert_eq!(strategy_guide.0[0].opponent, Choice::Rock);
+        assert_eq!(strategy_guide.0[0].me, Choice::Paper);
+        assert_eq!(strategy_guide.0[1].opponent, Choice::Paper);
+        assert_eq!(strategy_guide.0[1].me, Choice::Rock);
+        assert_eq!(strategy_guide.0[2].opponent, Choice::Scissors);
+        assert_eq!(strategy_guide.0[2].me, Choice::Scissors);
+
+        let MatchResult { me, opponent } = strategy_guide.points_scored();
+        assert_eq!(me, 15);
+        assert_eq!(opponent, 15);
+    }
+
+    #[test]
+    fn conforms_to_brief_step2() {
+        let strategy_guide = StrategyGuide::from_str_as_outcomes(BRIEF_INPUT).unwrap();
+
+        assert_eq!(strategy_guide.0[0].me, Choice::Rock);
+        assert_eq!(strategy_guide.0[1].me, Choice::Rock);
+        assert_eq!(strategy_guide.0[2].me, Choice::Rock);
+
+        let MatchResult { me, .. } = strategy_guide.points_scored();
+        assert_eq!(me, 12);
+    }
+
+    #[test]
+    fn as_outcome_guide() {
+        let strategy_guide: StrategyGuide = BRIEF_INPUT.parse().unwrap();
+        let outcome_guide = strategy_guide.as_outcome_guide();
+
+        assert_eq!(outcome_guide.points_scored().me, 12);
+        let expected = StrategyGuide::from_str_as_outcomes(BRIEF_INPUT).unwrap();
+        for (fight, expected) in outcome_guide.iter().zip(&expected) {
+            assert_eq!((fight.opponent, fight.me), (expected.opponent, expected.me));
+        }
+    }
+
+    const BRIEF_INPUT: &str = "A Y
+B X
+C Z
+";
+
+    #[test]
+    fn strategy_guide_collection_ergonomics() {
+        let strategy_guide: StrategyGuide = BRIEF_INPUT.parse().unwrap();
+        assert_eq!(strategy_guide.len(), 3);
+        assert!(!strategy_guide.is_empty());
+        assert!(StrategyGuide::default().is_empty());
+
+        let mut fights = 0;
+        for _fight in &strategy_guide {
+            fights += 1;
+        }
+        assert_eq!(fights, 3);
+        assert_eq!(
+            strategy_guide
+                .iter()
+                .map(|fight| fight.opponent)
+                .collect::<Vec<_>>(),
+            [Choice::Rock, Choice::Paper, Choice::Scissors]
+        );
+    }
+
+    #[test]
+    fn strategy_guide_from_str() {
+        let strategy_guide: StrategyGuide = BRIEF_INPUT.parse().unwrap();
+        assert_eq!(strategy_guide.0.len(), 3);
+
+        let MatchResult { me, opponent } = strategy_guide.points_scored();
+        assert_eq!(me, 15);
+        assert_eq!(opponent, 15);
+    }
+
+    #[test]
+    fn round_results() {
+        let strategy_guide: StrategyGuide = BRIEF_INPUT.parse().unwrap();
+        let rounds = strategy_guide.round_results();
+
+        assert_eq!(
+            rounds
+                .iter()
+                .map(|round| round.me_points)
+                .collect::<Vec<_>>(),
+            vec![8, 1, 6]
+        );
+        assert_eq!(
+            rounds.iter().map(|round| round.outcome).collect::<Vec<_>>(),
+            vec![
+                ChoiceFightOutcome::Win,
+                ChoiceFightOutcome::Loss,
+                ChoiceFightOutcome::Draw
+            ]
+        );
+    }
+
+    #[test]
+    fn outcome_tally() {
+        let strategy_guide: StrategyGuide = BRIEF_INPUT.parse().unwrap();
+        assert_eq!(
+            strategy_guide.outcome_tally(),
+            OutcomeTally {
+                wins: 1,
+                losses: 1,
+                draws: 1,
+            }
+        );
+    }
+
+    #[test]
+    fn move_frequencies() {
+        let strategy_guide: StrategyGuide = BRIEF_INPUT.parse().unwrap();
+        let each_once = [(Choice::Rock, 1), (Choice::Paper, 1), (Choice::Scissors, 1)];
+        assert_eq!(strategy_guide.opponent_move_frequency(), each_once);
+        assert_eq!(strategy_guide.my_move_frequency(), each_once);
+
+        let strategy_guide = StrategyGuide::from_str_as_outcomes(BRIEF_INPUT).unwrap();
+        assert_eq!(
+            strategy_guide.my_move_frequency(),
+            [(Choice::Rock, 3), (Choice::Paper, 0), (Choice::Scissors, 0)]
+        );
+    }
+
+    #[test]
+    fn max_possible_score() {
+        let strategy_guide: StrategyGuide = BRIEF_INPUT.parse().unwrap();
+        // Paper against Rock, Scissors against Paper then Rock against Scissors
+        assert_eq!(strategy_guide.max_possible_score(), 8 + 9 + 7);
+        assert_eq!(
+            strategy_guide.as_outcome_guide().max_possible_score(),
+            strategy_guide.max_possible_score()
+        );
+        assert_eq!(StrategyGuide::default().max_possible_score(), 0);
+    }
+
+    #[test]
+    fn match_result_winner() {
+        let strategy_guide: StrategyGuide = BRIEF_INPUT.parse().unwrap();
+        let result = strategy_guide.points_scored();
+        assert_eq!(result.winner(), None);
+        assert_eq!(result.margin(), 0);
+
+        let result = MatchResult {
+            me: 12,
+            opponent: 15,
+        };
+        assert_eq!(result.winner(), Some(Player::Opponent));
+        assert_eq!(result.margin(), 3);
+
+        let result = MatchResult {
+            me: 20,
+            opponent: 15,
+        };
+        assert_eq!(result.winner(), Some(Player::Me));
+        assert_eq!(result.margin(), 5);
+    }
+
+    #[test]
+    fn synthesize_roundtrips_target_score() {
+        for rounds in 1..=5 {
+            for target in rounds as u64..=rounds as u64 * 9 {
+                let strategy_guide = StrategyGuide::synthesize(target, rounds).unwrap();
+                assert_eq!(strategy_guide.0.len(), rounds);
+                assert_eq!(strategy_guide.points_scored().me, target);
+            }
+        }
+
+        assert_eq!(StrategyGuide::synthesize(0, 0).unwrap().0.len(), 0);
+    }
+
+    #[test]
+    fn synthesize_rejects_unreachable_scores() {
+        assert!(matches!(
+            StrategyGuide::synthesize(2, 3),
+            Err(Day2Error::UnreachableScore {
+                min: 3,
+                max: 27,
+                ..
+            })
+        ));
+        assert!(matches!(
+            StrategyGuide::synthesize(28, 3),
+            Err(Day2Error::UnreachableScore { .. })
+        ));
+        assert!(matches!(
+            StrategyGuide::synthesize(1, 0),
+            Err(Day2Error::UnreachableScore { .. })
+        ));
+    }
+
+    #[test]
+    fn to_input_string_roundtrip() {
+        let strategy_guide: StrategyGuide = BRIEF_INPUT.parse().unwrap();
+        let input = strategy_guide.to_input_string();
+        assert_eq!(input, "A Y\nB X\nC Z\n");
+
+        let roundtripped: StrategyGuide = input.parse().unwrap();
+        assert_eq!(roundtripped.points_scored(), strategy_guide.points_scored());
+
+        let synthesized = StrategyGuide::synthesize(42, 7).unwrap();
+        let roundtripped: StrategyGuide = synthesized.to_input_string().parse().unwrap();
+        assert_eq!(roundtripped.points_scored().me, 42);
+    }
+
+    #[test]
+    fn bytes_roundtrip() {
+        let strategy_guide = StrategyGuide::from_str_as_outcomes(BRIEF_INPUT).unwrap();
+        let bytes = strategy_guide.to_bytes();
+        assert_eq!(bytes, vec![3, 0, 0, 0, 1, 1, 2, 1, 3, 1]);
+
+        let roundtripped = StrategyGuide::from_bytes(&bytes).unwrap();
+        let MatchResult { me, opponent } = roundtripped.points_scored();
+        let expected = strategy_guide.points_scored();
+        assert_eq!((me, opponent), (expected.me, expected.opponent));
+
+        assert!(matches!(
+            StrategyGuide::from_bytes(&[3, 0]),
+            Err(Day2Error::MissingLengthPrefix)
+        ));
+        assert!(matches!(
+            StrategyGuide::from_bytes(&[2, 0, 0, 0, 1, 1]),
+            Err(Day2Error::LengthMismatch {
+                announced: 2,
+                actual: 2
+            })
+        ));
+        assert!(matches!(
+            StrategyGuide::from_bytes(&[1, 0, 0, 0, 1, 4]),
+            Err(Day2Error::InvalidChoiceByte(4))
+        ));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_json_roundtrip() {
+        let strategy_guide: StrategyGuide = BRIEF_INPUT.parse().unwrap();
+        let json = serde_json::to_string(&strategy_guide).unwrap();
+        assert!(json.starts_with(r#"[{"opponent":"Rock","me":"Paper"}"#));
+
+        let roundtripped: StrategyGuide = serde_json::from_str(&json).unwrap();
+        let MatchResult { me, opponent } = roundtripped.points_scored();
+        assert_eq!((me, opponent), (15, 15));
+    }
+
+    #[test]
+    fn play_interactive_deterministic_game() {
+        use rand::SeedableRng as _;
+
+        let mut rng = rand::rngs::StdRng::seed_from_u64(2022);
+        let mut replay_rng = rng.clone();
+
+        let input = std::io::Cursor::new("r\nlizard\nPaper\ns\n");
+        let mut output = vec![];
+        let result = play_interactive(input, &mut output, &mut rng).unwrap();
+
+        let expected = StrategyGuide(
+            [Choice::Rock, Choice::Paper, Choice::Scissors]
+                .into_iter()
+                .map(|me| ChoiceFight {
+                    opponent: random_choice(&mut replay_rng),
+                    me,
+                })
+                .collect(),
+        )
+        .points_scored();
+        assert_eq!(
+            (result.me, result.opponent),
+            (expected.me, expected.opponent)
+        );
+
+        let output = String::from_utf8(output).unwrap();
+        assert!(output.contains("Unknown move `lizard`"));
+        assert_eq!(output.matches("You played").count(), 3);
+        assert!(output.contains("Round 4"));
+    }
+
+    #[test]
+    fn play_interactive_quits() {
+        let mut rng = rand::thread_rng();
+        let input = std::io::Cursor::new("q\nr\n");
+        let result = play_interactive(input, std::io::sink(), &mut rng).unwrap();
+        assert_eq!((result.me, result.opponent), (0, 0));
+    }
+
+    #[test]
+    fn strategy_guide_from_str_rejects_bad_column_count() {
+        assert!(matches!(
+            "A Y\nB\nC Z".parse::<StrategyGuide>(),
+            Err(Day2Error::WrongColumnCount { found: 1, .. })
+        ));
+        assert!(matches!(
+            StrategyGuide::from_str_as_outcomes("A Y\nB X Y\n"),
+            Err(Day2Error::WrongColumnCount { found: 3, .. })
+        ));
+    }
+
+    #[test]
+    fn choices_and_outcomes_from_bytes() {
+        assert_eq!(Choice::from_byte(b'A').unwrap(), Choice::Rock);
+        assert_eq!(Choice::from_byte(b'Y').unwrap(), Choice::Paper);
+        assert_eq!(Choice::from_byte(b'Z').unwrap(), Choice::Scissors);
+        assert!(matches!(
+            Choice::from_byte(b'Q'),
+            Err(Day2Error::UnknownChoiceLetter(b'Q'))
+        ));
+
+        assert_eq!(
+            ChoiceFightOutcome::from_byte(b'X').unwrap(),
+            ChoiceFightOutcome::Loss
+        );
+        assert_eq!(
+            ChoiceFightOutcome::from_byte(b'Z').unwrap(),
+            ChoiceFightOutcome::Win
+        );
+        assert!(matches!(
+            ChoiceFightOutcome::from_byte(b'A'),
+            Err(Day2Error::UnknownOutcomeLetter(b'A'))
+        ));
+
+        // Every single letter parses the same either way
+        for b in b'A'..=b'Z' {
+            let letter = char::from(b).to_string();
+            assert_eq!(
+                Choice::from_byte(b).ok(),
+                letter.parse::<Choice>().ok(),
+                "{letter}"
+            );
+            assert_eq!(
+                ChoiceFightOutcome::from_byte(b).ok(),
+                letter.parse::<ChoiceFightOutcome>().ok(),
+                "{letter}"
+            );
+        }
+    }
+
+    #[test]
+    fn strategy_guide_from_ascii() {
+        let fights = |guide: &StrategyGuide| {
+            guide
+                .iter()
+                .map(|fight| (fight.opponent, fight.me))
+                .collect::<Vec<_>>()
+        };
+        let guide = StrategyGuide::from_ascii(BRIEF_INPUT.as_bytes()).unwrap();
+        let expected: StrategyGuide = BRIEF_INPUT.parse().unwrap();
+        assert_eq!(fights(&guide), fights(&expected));
+
+        let guide = StrategyGuide::from_ascii(b"A, Y\r\n\n  B\tX\r\n").unwrap();
+        assert_eq!(guide.points_scored().me, 8 + 1);
+
+        assert!(matches!(
+            StrategyGuide::from_ascii(b"A Y\nB\nC Z"),
+            Err(Day2Error::WrongColumnCount { found: 1, .. })
+        ));
+        assert!(matches!(
+            StrategyGuide::from_ascii(b"A Y X Z"),
+            Err(Day2Error::WrongColumnCount { found: 4, .. })
+        ));
+        assert!(matches!(
+            StrategyGuide::from_ascii(b"A YZ"),
+            Err(Day2Error::NotASingleLetter { .. })
+        ));
+        assert!(matches!(
+            StrategyGuide::from_ascii(b"A B"),
+            Err(Day2Error::UnknownOutcomeLetter(b'B'))
+        ));
+    }
+
+    #[test]
+    fn strategy_line_keeps_both_interpretations() {
+        let line: StrategyLine = "A Y".parse().unwrap();
+        assert_eq!(
+            line,
+            StrategyLine {
+                opponent: Choice::Rock,
+                as_choice: Choice::Paper,
+                as_outcome: ChoiceFightOutcome::Draw,
+            }
+        );
+    }
+
+    #[test]
+    fn strategy_line_accepts_any_column_separator() {
+        let expected: StrategyLine = "A Y".parse().unwrap();
+        for line in ["A\tY", "A   Y", "  A \t Y  ", "A,Y", "A, Y"] {
+            assert_eq!(line.parse::<StrategyLine>().unwrap(), expected);
+        }
+
+        let strategy_guide: StrategyGuide = "A\tY\nB  X\nC,Z\n".parse().unwrap();
+        assert_eq!(strategy_guide.points_scored().me, 15);
+    }
+
+    #[test]
+    fn strategy_line_rejects_malformed_columns() {
+        assert!(matches!(
+            "A W".parse::<StrategyLine>(),
+            Err(Day2Error::InvalidChoice { .. })
+        ));
+        assert!(matches!(
+            "A B".parse::<StrategyLine>(),
+            Err(Day2Error::InvalidOutcome { .. })
+        ));
+        assert!(matches!(
+            "Q Y".parse::<StrategyLine>(),
+            Err(Day2Error::InvalidOpponentChoice { .. })
+        ));
+        assert!(matches!(
+            "A".parse::<StrategyLine>(),
+            Err(Day2Error::WrongColumnCount { found: 1, .. })
+        ));
+        assert!(matches!(
+            "A Y Z".parse::<StrategyLine>(),
+            Err(Day2Error::WrongColumnCount { found: 3, .. })
+        ));
+    }
+}
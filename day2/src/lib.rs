@@ -0,0 +1,11 @@
+//! Pure scoring logic lives in `scoring` and builds without `std`: disabling the default `std`
+//! feature leaves only that part, dropping the strategy guide parsing and the interactive game.
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+mod scoring;
+pub use scoring::*;
+
+#[cfg(feature = "std")]
+mod guide;
+#[cfg(feature = "std")]
+pub use guide::*;
@@ -0,0 +1,633 @@
+#[derive(
+    Debug, Clone, Copy, Eq, PartialEq, strum::EnumString, strum::AsRefStr, strum::EnumIter,
+)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(u64)]
+pub enum Choice {
+    #[strum(serialize = "A", serialize = "X")]
+    Rock = 1,
+    #[strum(serialize = "B", serialize = "Y")]
+    Paper = 2,
+    #[strum(serialize = "C", serialize = "Z")]
+    Scissors = 3,
+}
+
+impl Choice {
+    /// Every choice, from Rock to Scissors
+    pub fn all() -> impl Iterator<Item = Choice> {
+        <Self as strum::IntoEnumIterator>::iter()
+    }
+
+    pub fn wins_against(&self, other: Choice) -> bool {
+        self.beats() == other
+    }
+
+    /// The choice this one wins against
+    pub fn beats(&self) -> Choice {
+        match self {
+            Self::Rock => Self::Scissors,
+            Self::Paper => Self::Rock,
+            Self::Scissors => Self::Paper,
+        }
+    }
+
+    /// The choice this one loses against
+    pub fn loses_against(&self) -> Choice {
+        match self {
+            Self::Rock => Self::Paper,
+            Self::Paper => Self::Scissors,
+            Self::Scissors => Self::Rock,
+        }
+    }
+
+    pub fn solve_outcome(&self, desired_outcome: &ChoiceFightOutcome) -> Self {
+        match desired_outcome {
+            ChoiceFightOutcome::Loss => self.beats(),
+            ChoiceFightOutcome::Draw => *self,
+            ChoiceFightOutcome::Win => self.loses_against(),
+        }
+    }
+
+    pub fn points(&self) -> u64 {
+        *self as u64
+    }
+
+    pub fn from_points(points: u64) -> Option<Self> {
+        Classic
+            .choices()
+            .iter()
+            .copied()
+            .find(|choice| choice.points() == points)
+    }
+}
+
+impl core::fmt::Display for Choice {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(match self {
+            Self::Rock => "Rock",
+            Self::Paper => "Paper",
+            Self::Scissors => "Scissors",
+        })
+    }
+}
+
+/// Rules of a Rock-Paper-Scissors-like game
+pub trait RpsVariant {
+    type Choice: Copy + Eq;
+
+    /// Every choice a player can make in this variant
+    fn choices(&self) -> &[Self::Choice];
+
+    fn wins_against(&self, choice: Self::Choice, other: Self::Choice) -> bool;
+
+    fn outcome(&self, me: Self::Choice, opponent: Self::Choice) -> ChoiceFightOutcome {
+        if me == opponent {
+            ChoiceFightOutcome::Draw
+        } else if self.wins_against(me, opponent) {
+            ChoiceFightOutcome::Win
+        } else {
+            ChoiceFightOutcome::Loss
+        }
+    }
+
+    /// Finds the choice to play against `opponent` to get `desired_outcome`, if any
+    fn solve_outcome(
+        &self,
+        opponent: Self::Choice,
+        desired_outcome: &ChoiceFightOutcome,
+    ) -> Option<Self::Choice> {
+        self.choices()
+            .iter()
+            .copied()
+            .find(|me| self.outcome(*me, opponent) == *desired_outcome)
+    }
+}
+
+/// The classic 3-choice Rock-Paper-Scissors, whose rules are the ones of `Choice`
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Classic;
+
+impl RpsVariant for Classic {
+    type Choice = Choice;
+
+    fn choices(&self) -> &[Choice] {
+        &[Choice::Rock, Choice::Paper, Choice::Scissors]
+    }
+
+    fn wins_against(&self, choice: Choice, other: Choice) -> bool {
+        choice.wins_against(other)
+    }
+
+    fn solve_outcome(
+        &self,
+        opponent: Choice,
+        desired_outcome: &ChoiceFightOutcome,
+    ) -> Option<Choice> {
+        Some(opponent.solve_outcome(desired_outcome))
+    }
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum LizardSpockChoice {
+    Rock,
+    Paper,
+    Scissors,
+    Lizard,
+    Spock,
+}
+
+/// Rock-Paper-Scissors-Lizard-Spock, where every choice beats two others and loses to the rest
+#[derive(Debug, Clone, Copy, Default)]
+pub struct LizardSpock;
+
+impl RpsVariant for LizardSpock {
+    type Choice = LizardSpockChoice;
+
+    fn choices(&self) -> &[LizardSpockChoice] {
+        use LizardSpockChoice::*;
+        &[Rock, Paper, Scissors, Lizard, Spock]
+    }
+
+    fn wins_against(&self, choice: LizardSpockChoice, other: LizardSpockChoice) -> bool {
+        use LizardSpockChoice::*;
+        match choice {
+            Rock => matches!(other, Scissors | Lizard),
+            Paper => matches!(other, Rock | Spock),
+            Scissors => matches!(other, Paper | Lizard),
+            Lizard => matches!(other, Paper | Spock),
+            Spock => matches!(other, Rock | Scissors),
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, strum::EnumString, strum::AsRefStr)]
+#[repr(u64)]
+pub enum ChoiceFightOutcome {
+    #[strum(serialize = "X")]
+    Loss = 0,
+    #[strum(serialize = "Y")]
+    Draw = 3,
+    #[strum(serialize = "Z")]
+    Win = 6,
+}
+
+impl ChoiceFightOutcome {
+    pub fn points(&self) -> u64 {
+        *self as u64
+    }
+
+    /// The outcome worth `points`, if any
+    pub fn from_score(points: u64) -> Option<Self> {
+        [Self::Loss, Self::Draw, Self::Win]
+            .into_iter()
+            .find(|outcome| outcome.points() == points)
+    }
+}
+
+impl core::fmt::Display for ChoiceFightOutcome {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(match self {
+            Self::Loss => "Loss",
+            Self::Draw => "Draw",
+            Self::Win => "Win",
+        })
+    }
+}
+
+/// A single fight, between choices of the classic game unless stated otherwise
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ChoiceFight<C = Choice> {
+    pub opponent: C,
+    pub me: C,
+}
+
+impl<C: Copy> ChoiceFight<C> {
+    /// My outcome under the rules of `variant`
+    pub fn outcome_in(&self, variant: &impl RpsVariant<Choice = C>) -> ChoiceFightOutcome {
+        variant.outcome(self.me, self.opponent)
+    }
+}
+
+impl ChoiceFight {
+    pub fn outcome(&self) -> ChoiceFightOutcome {
+        self.outcome_in(&Classic)
+    }
+
+    /// My points for the round: my choice's points plus my outcome's
+    pub fn score_me(&self) -> u64 {
+        self.me.points() + self.outcome().points()
+    }
+
+    /// The opponent's points for the round, scored like mine from their side of the fight
+    pub fn score_opponent(&self) -> u64 {
+        ChoiceFight {
+            opponent: self.me,
+            me: self.opponent,
+        }
+        .score_me()
+    }
+
+    pub fn round_result(&self) -> RoundResult {
+        RoundResult {
+            me_points: self.score_me(),
+            opponent_points: self.score_opponent(),
+            outcome: self.outcome(),
+        }
+    }
+}
+
+/// Points scored by each player during a single fight, `outcome` being from my point of view
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RoundResult {
+    pub me_points: u64,
+    pub opponent_points: u64,
+    pub outcome: ChoiceFightOutcome,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Player {
+    Me,
+    Opponent,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct MatchResult {
+    pub opponent: u64,
+    pub me: u64,
+}
+
+impl MatchResult {
+    /// Returns `None` when the match is a tie
+    pub fn winner(&self) -> Option<Player> {
+        match self.me.cmp(&self.opponent) {
+            core::cmp::Ordering::Greater => Some(Player::Me),
+            core::cmp::Ordering::Less => Some(Player::Opponent),
+            core::cmp::Ordering::Equal => None,
+        }
+    }
+
+    pub fn margin(&self) -> u64 {
+        self.me.abs_diff(self.opponent)
+    }
+}
+
+/// Scoreboard of the match, scores on the first line and the winner on the second
+impl core::fmt::Display for MatchResult {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        writeln!(f, "Me: {} | Opponent: {}", self.me, self.opponent)?;
+        f.write_str(match self.winner() {
+            Some(Player::Me) => "Winner: Me",
+            Some(Player::Opponent) => "Winner: Opponent",
+            None => "Winner: Nobody, it's a tie",
+        })
+    }
+}
+
+impl core::ops::Add for MatchResult {
+    type Output = Self;
+
+    fn add(mut self, other: Self) -> Self {
+        self += other;
+        self
+    }
+}
+
+impl core::ops::AddAssign for MatchResult {
+    fn add_assign(&mut self, other: Self) {
+        self.me += other.me;
+        self.opponent += other.opponent;
+    }
+}
+
+impl core::iter::Sum for MatchResult {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::default(), core::ops::Add::add)
+    }
+}
+
+/// Outcome of every fight, from my point of view, indexed by my choice then the opponent's in
+/// `Choice::all` order
+pub fn payoff_matrix() -> [[ChoiceFightOutcome; 3]; 3] {
+    let mut matrix = [[ChoiceFightOutcome::Draw; 3]; 3];
+    for (row, me) in matrix.iter_mut().zip(Choice::all()) {
+        for (cell, opponent) in row.iter_mut().zip(Choice::all()) {
+            *cell = ChoiceFight { opponent, me }.outcome();
+        }
+    }
+    matrix
+}
+
+/// The choice scoring the most on average against an opponent playing each choice with the
+/// paired probability, along with that expected score
+pub fn best_fixed_choice(opponent_dist: &[(Choice, f64)]) -> (Choice, f64) {
+    Choice::all()
+        .map(|me| {
+            let expected = opponent_dist
+                .iter()
+                .map(|(opponent, probability)| {
+                    probability
+                        * ChoiceFight {
+                            opponent: *opponent,
+                            me,
+                        }
+                        .score_me() as f64
+                })
+                .sum();
+            (me, expected)
+        })
+        .max_by(|(_, a): &(Choice, f64), (_, b)| a.total_cmp(b))
+        .expect("There's always a choice to make")
+}
+
+/// Plays my strategy, called with each round's index, against a scripted opponent
+pub fn simulate(my_strategy: impl Fn(usize) -> Choice, opponent_script: &[Choice]) -> MatchResult {
+    opponent_script
+        .iter()
+        .enumerate()
+        .map(|(round, opponent)| {
+            let round_result = ChoiceFight {
+                opponent: *opponent,
+                me: my_strategy(round),
+            }
+            .round_result();
+            MatchResult {
+                me: round_result.me_points,
+                opponent: round_result.opponent_points,
+            }
+        })
+        .sum()
+}
+
+pub fn random_choice(rng: &mut impl rand::Rng) -> Choice {
+    Classic.choices()[rng.gen_range(0..Classic.choices().len())]
+}
+
+/// Rounds won, lost and drawn, from my point of view
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct OutcomeTally {
+    pub wins: usize,
+    pub losses: usize,
+    pub draws: usize,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn payoff_matrix() {
+        assert_eq!(
+            Choice::all().collect::<Vec<_>>(),
+            [Choice::Rock, Choice::Paper, Choice::Scissors]
+        );
+
+        let matrix = super::payoff_matrix();
+        for (i, row) in matrix.iter().enumerate() {
+            assert_eq!(row[i], ChoiceFightOutcome::Draw);
+        }
+        let wins = matrix
+            .iter()
+            .flatten()
+            .filter(|outcome| **outcome == ChoiceFightOutcome::Win)
+            .count();
+        assert_eq!(wins, 3);
+        // Paper covers Rock
+        assert_eq!(matrix[1][0], ChoiceFightOutcome::Win);
+    }
+
+    #[test]
+    fn match_results_add_up() {
+        let first = MatchResult {
+            me: 15,
+            opponent: 12,
+        };
+        let second = MatchResult { me: 4, opponent: 8 };
+        let expected = MatchResult {
+            me: 19,
+            opponent: 20,
+        };
+
+        assert_eq!(first + second, expected);
+        let mut total = first;
+        total += second;
+        assert_eq!(total, expected);
+        assert_eq!([first, second].into_iter().sum::<MatchResult>(), expected);
+        assert_eq!(
+            core::iter::empty::<MatchResult>().sum::<MatchResult>(),
+            MatchResult::default()
+        );
+    }
+
+    #[test]
+    fn simulate_always_rock() {
+        let result = simulate(
+            |_| Choice::Rock,
+            &[Choice::Scissors, Choice::Scissors, Choice::Rock],
+        );
+        // Two wins worth 1 + 6 each, then a draw worth 1 + 3 to both players
+        assert_eq!(
+            result,
+            MatchResult {
+                me: 18,
+                opponent: 10,
+            }
+        );
+        assert_eq!(result.winner(), Some(Player::Me));
+
+        let cycling = |round: usize| Choice::all().nth(round % 3).unwrap();
+        assert_eq!(simulate(cycling, &[]), MatchResult::default());
+        assert_eq!(
+            simulate(cycling, &[Choice::Rock, Choice::Paper, Choice::Scissors]).me,
+            15
+        );
+    }
+
+    #[test]
+    fn fight_outcome_without_std() {
+        let fight = ChoiceFight {
+            opponent: Choice::Rock,
+            me: Choice::Paper,
+        };
+        assert_eq!(fight.outcome(), ChoiceFightOutcome::Win);
+        assert_eq!(fight.round_result().me_points, 8);
+
+        let fight = ChoiceFight {
+            opponent: Choice::Rock,
+            me: Choice::Scissors,
+        };
+        assert_eq!(fight.outcome(), ChoiceFightOutcome::Loss);
+    }
+
+    #[test]
+    fn best_fixed_choice() {
+        // Every choice is worth 3 outcome points on average, so the shape points decide
+        let uniform = Choice::all().map(|c| (c, 1.0 / 3.0)).collect::<Vec<_>>();
+        let (choice, expected) = super::best_fixed_choice(&uniform);
+        assert_eq!(choice, Choice::Scissors);
+        assert!((expected - 6.0).abs() < 1e-9);
+
+        let (choice, expected) = super::best_fixed_choice(&[
+            (Choice::Rock, 0.8),
+            (Choice::Paper, 0.1),
+            (Choice::Scissors, 0.1),
+        ]);
+        assert_eq!(choice, Choice::Paper);
+        assert!((expected - (2.0 + 0.8 * 6.0 + 0.1 * 3.0)).abs() < 1e-9);
+    }
+
+    #[test]
+    fn match_result_scoreboard() {
+        let tie = MatchResult {
+            me: 15,
+            opponent: 15,
+        };
+        assert_eq!(
+            format!("{tie}"),
+            "Me: 15 | Opponent: 15\nWinner: Nobody, it's a tie"
+        );
+
+        let won = MatchResult {
+            me: 20,
+            opponent: 15,
+        };
+        assert_eq!(format!("{won}"), "Me: 20 | Opponent: 15\nWinner: Me");
+    }
+
+    #[test]
+    fn outcome_from_score() {
+        assert_eq!(
+            ChoiceFightOutcome::from_score(6),
+            Some(ChoiceFightOutcome::Win)
+        );
+        assert_eq!(
+            ChoiceFightOutcome::from_score(3),
+            Some(ChoiceFightOutcome::Draw)
+        );
+        assert_eq!(
+            ChoiceFightOutcome::from_score(0),
+            Some(ChoiceFightOutcome::Loss)
+        );
+        assert_eq!(ChoiceFightOutcome::from_score(4), None);
+    }
+
+    #[test]
+    fn score_each_player() {
+        let fight = ChoiceFight {
+            opponent: Choice::Scissors,
+            me: Choice::Rock,
+        };
+        assert_eq!(fight.score_me(), 1 + 6);
+        assert_eq!(fight.score_opponent(), 3);
+
+        let draw = ChoiceFight {
+            opponent: Choice::Paper,
+            me: Choice::Paper,
+        };
+        assert_eq!((draw.score_me(), draw.score_opponent()), (5, 5));
+    }
+
+    #[test]
+    fn display_human_readable_names() {
+        assert_eq!(format!("{}", Choice::Rock), "Rock");
+        assert_eq!(Choice::Paper.to_string(), "Paper");
+        assert_eq!(Choice::Scissors.to_string(), "Scissors");
+        assert_eq!(ChoiceFightOutcome::Loss.to_string(), "Loss");
+        assert_eq!(ChoiceFightOutcome::Draw.to_string(), "Draw");
+        assert_eq!(ChoiceFightOutcome::Win.to_string(), "Win");
+
+        // Parsing tokens are left untouched
+        assert_eq!("A".parse::<Choice>().unwrap(), Choice::Rock);
+        assert_eq!(ChoiceFightOutcome::Win.as_ref(), "Z");
+    }
+
+    #[test]
+    fn beats_and_loses_against() {
+        assert_eq!(Choice::Rock.beats(), Choice::Scissors);
+        assert_eq!(Choice::Rock.loses_against(), Choice::Paper);
+
+        for choice in Classic.choices() {
+            assert!(choice.wins_against(choice.beats()));
+            assert!(choice.loses_against().wins_against(*choice));
+            assert_eq!(choice.beats().beats(), choice.loses_against());
+        }
+    }
+
+    #[test]
+    fn classic_variant_is_solvable() {
+        for opponent in Classic.choices() {
+            for desired in [
+                ChoiceFightOutcome::Loss,
+                ChoiceFightOutcome::Draw,
+                ChoiceFightOutcome::Win,
+            ] {
+                let me = opponent.solve_outcome(&desired);
+                assert_eq!(Classic.outcome(me, *opponent), desired);
+            }
+        }
+    }
+
+    #[test]
+    fn lizard_spock_variant() {
+        use LizardSpockChoice::*;
+
+        assert!(LizardSpock.wins_against(Spock, Scissors));
+        assert!(LizardSpock.wins_against(Spock, Rock));
+        assert!(!LizardSpock.wins_against(Spock, Lizard));
+        assert!(!LizardSpock.wins_against(Spock, Paper));
+        assert_eq!(LizardSpock.outcome(Spock, Lizard), ChoiceFightOutcome::Loss);
+        assert_eq!(LizardSpock.outcome(Spock, Paper), ChoiceFightOutcome::Loss);
+        assert_eq!(LizardSpock.outcome(Spock, Spock), ChoiceFightOutcome::Draw);
+
+        let fight = ChoiceFight {
+            opponent: Lizard,
+            me: Spock,
+        };
+        assert_eq!(fight.outcome_in(&LizardSpock), ChoiceFightOutcome::Loss);
+
+        // Every choice beats exactly two others
+        for choice in LizardSpock.choices() {
+            let wins = LizardSpock
+                .choices()
+                .iter()
+                .filter(|other| LizardSpock.wins_against(*choice, **other))
+                .count();
+            assert_eq!(wins, 2);
+        }
+
+        assert!(matches!(
+            LizardSpock.solve_outcome(Spock, &ChoiceFightOutcome::Win),
+            Some(Paper | Lizard)
+        ));
+    }
+
+    mod prop {
+        use super::*;
+        use proptest::prelude::*;
+
+        fn any_choice() -> impl Strategy<Value = Choice> {
+            prop_oneof![
+                Just(Choice::Rock),
+                Just(Choice::Paper),
+                Just(Choice::Scissors),
+            ]
+        }
+
+        fn any_outcome() -> impl Strategy<Value = ChoiceFightOutcome> {
+            prop_oneof![
+                Just(ChoiceFightOutcome::Loss),
+                Just(ChoiceFightOutcome::Draw),
+                Just(ChoiceFightOutcome::Win),
+            ]
+        }
+
+        proptest! {
+            #[test]
+            fn solve_outcome_achieves_desired_outcome(opponent in any_choice(), desired in any_outcome()) {
+                let me = opponent.solve_outcome(&desired);
+                let fight = ChoiceFight { opponent, me };
+                prop_assert_eq!(fight.outcome(), desired);
+            }
+        }
+    }
+}
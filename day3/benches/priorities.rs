@@ -0,0 +1,48 @@
+//! Compare the computed priorities against the linear alphabet scan by running this bench
+//! without, then with, the `linear_priorities` feature: criterion reports the change between
+//! both runs.
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion, Throughput};
+use day3::{Priorities, RucksackGroup};
+
+const RUCKSACKS: usize = 10_000;
+
+/// Pseudo-random rucksacks, always the same ones so that runs can be compared
+fn synthetic_rucksacks() -> String {
+    let alphabet: Vec<char> = ('a'..='z').chain('A'..='Z').collect();
+    let mut seed = 0x2022_u64;
+    let mut next = move || {
+        // Numerical Recipes' LCG, plenty for picking items
+        seed = seed
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        (seed >> 33) as usize
+    };
+
+    let mut rucksacks = String::new();
+    for _ in 0..RUCKSACKS {
+        let half = 8 + next() % 17;
+        for _ in 0..half * 2 {
+            rucksacks.push(alphabet[next() % alphabet.len()]);
+        }
+        rucksacks.push('\n');
+    }
+    rucksacks
+}
+
+fn priorities(c: &mut Criterion) {
+    let rucksacks = synthetic_rucksacks();
+    let items = rucksacks.lines().map(str::len).sum::<usize>();
+    let rucksack_group: RucksackGroup = rucksacks.parse().unwrap();
+    let priorities = Priorities::default();
+
+    let mut group = c.benchmark_group("priorities");
+    group.throughput(Throughput::Elements(items as u64));
+    group.bench_function("cumulated_priority_sum", |b| {
+        b.iter(|| rucksack_group.cumulated_priority_sum(black_box(&priorities)))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, priorities);
+criterion_main!(benches);
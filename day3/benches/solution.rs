@@ -0,0 +1,28 @@
+use aoc_core::Solution;
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use day3::{Day3, Priorities, Rucksack};
+
+/// Brief example repeated, so that the benches don't need the real puzzle input
+const FIXTURE: &str = include_str!("fixture.txt");
+
+fn solution(c: &mut Criterion) {
+    let input = Day3::parse(FIXTURE).unwrap();
+
+    c.bench_function("day3 parse", |b| b.iter(|| Day3::parse(black_box(FIXTURE))));
+    c.bench_function("day3 parse_bytes part1", |b| {
+        let priorities = Priorities::default();
+        b.iter(|| {
+            black_box(FIXTURE)
+                .lines()
+                .map(|line| {
+                    Rucksack::parse_bytes(line.as_bytes())?.common_items_priority(&priorities)
+                })
+                .sum::<day3::Result<u64>>()
+        })
+    });
+    c.bench_function("day3 part1", |b| b.iter(|| Day3::part1(black_box(&input))));
+    c.bench_function("day3 part2", |b| b.iter(|| Day3::part2(black_box(&input))));
+}
+
+criterion_group!(benches, solution);
+criterion_main!(benches);
@@ -0,0 +1,11 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+// Any UTF-8 input must either parse or be rejected, never panic
+fuzz_target!(|input: &str| {
+    if let Ok(rucksack) = input.parse::<day3::Rucksack>() {
+        assert_eq!(rucksack.to_string(), input);
+        let _ = rucksack.common_items();
+    }
+});
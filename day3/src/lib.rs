@@ -0,0 +1,1451 @@
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+
+#[cfg(feature = "wasm")]
+pub mod wasm;
+
+#[derive(Debug, thiserror::Error)]
+pub enum Day3Error {
+    #[error("Item `{0}` has no priority, it's not part of the alphabet")]
+    UnknownItem(char),
+    #[error("Item `{item}` appears twice in the alphabet, at priorities {first} and {second}")]
+    DuplicatePriority { item: char, first: u8, second: u8 },
+    #[error("Item `{item}` has priority {priority} which does not fit in a bitmask")]
+    PriorityExceedsBitmask { item: char, priority: u8 },
+    #[error("Rucksacks need at least one compartment!")]
+    NoCompartments,
+    #[error("Rucksack contents are not even. Cannot split into {compartments} compartments!")]
+    UnevenRucksack { items: usize, compartments: usize },
+    #[error("Cannot split rucksack `{rucksack}` at {index}, past its {len} bytes")]
+    SplitOutOfRange {
+        rucksack: String,
+        index: usize,
+        len: usize,
+    },
+    #[error("Cannot split rucksack `{rucksack}` at {index}, which is inside an item")]
+    SplitInsideItem { rucksack: String, index: usize },
+    #[error("Rucksack `{rucksack}` contains `{item}`, only ASCII letters are valid items!")]
+    InvalidItem { rucksack: String, item: char },
+    #[error("Invalid rucksack on line {line}")]
+    InvalidRucksackLine {
+        line: usize,
+        #[source]
+        source: Box<Day3Error>,
+    },
+    #[error("Cannot read rucksack file {}", path.display())]
+    UnreadableFile {
+        path: std::path::PathBuf,
+        #[source]
+        source: std::io::Error,
+    },
+    #[error("Invalid rucksack file {}", path.display())]
+    InvalidFile {
+        path: std::path::PathBuf,
+        #[source]
+        source: Box<Day3Error>,
+    },
+    #[error("Rucksack #{index} ({rucksack}) has no item in common between its compartments")]
+    NoCommonItem { index: usize, rucksack: String },
+    #[error("Rucksack groups cannot be empty!")]
+    EmptyGroup,
+    #[error("{rucksacks} rucksacks cannot be split into groups of {size}, {leftover} would be left over")]
+    IncompleteGroup {
+        rucksacks: usize,
+        size: usize,
+        leftover: usize,
+    },
+}
+
+pub type Result<T, E = Day3Error> = std::result::Result<T, E>;
+
+/// Item priorities, each item of the alphabet being worth its 1-based position in it
+#[derive(Debug, Clone)]
+pub struct Priorities {
+    by_item: HashMap<char, u8>,
+    /// Items ordered by priority, for the reverse lookup
+    alphabet: Vec<char>,
+    /// Whether `alphabet` is the classic one, whose priorities can be computed
+    #[cfg_attr(feature = "linear_priorities", allow(dead_code))]
+    classic: bool,
+}
+
+impl Priorities {
+    /// Errors if an item appears twice, as it would be worth two priorities.
+    /// Panics if `alphabet` holds more than `u8::MAX` items, as priorities wouldn't fit anymore
+    pub fn from_alphabet(alphabet: &[char]) -> Result<Self> {
+        assert!(
+            alphabet.len() <= u8::MAX as usize,
+            "Alphabets cannot hold more than {} items",
+            u8::MAX
+        );
+
+        let mut by_item = HashMap::with_capacity(alphabet.len());
+        for (pos, &item) in alphabet.iter().enumerate() {
+            // SAFETY: Safe because we checked the alphabet length above so it cannot ever overflow u8::MAX
+            let priority = (pos + 1) as u8;
+            if let Some(first) = by_item.insert(item, priority) {
+                return Err(Day3Error::DuplicatePriority {
+                    item,
+                    first,
+                    second: priority,
+                });
+            }
+        }
+
+        Ok(Self {
+            by_item,
+            alphabet: alphabet.to_vec(),
+            classic: alphabet.iter().copied().eq(('a'..='z').chain('A'..='Z')),
+        })
+    }
+
+    pub fn len(&self) -> usize {
+        self.by_item.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.by_item.is_empty()
+    }
+
+    /// Whether every priority can be represented by `Container::to_bitmask`
+    pub fn fits_bitmask(&self) -> bool {
+        self.len() <= u64::BITS as usize
+    }
+
+    #[cfg(not(feature = "linear_priorities"))]
+    pub fn priority_for_char(&self, c: char) -> Result<u8> {
+        let priority = if self.classic {
+            match c {
+                'a'..='z' => Some(c as u8 - b'a' + 1),
+                'A'..='Z' => Some(c as u8 - b'A' + 27),
+                _ => None,
+            }
+        } else {
+            self.by_item.get(&c).copied()
+        };
+        priority.ok_or(Day3Error::UnknownItem(c))
+    }
+
+    /// Scans the alphabet for `c`, only kept around for the benches to compare against
+    #[cfg(feature = "linear_priorities")]
+    pub fn priority_for_char(&self, c: char) -> Result<u8> {
+        self.alphabet
+            .iter()
+            .position(|&item| item == c)
+            .map(|pos| (pos + 1) as u8)
+            .ok_or(Day3Error::UnknownItem(c))
+    }
+
+    /// Item worth `priority`, if any, priorities starting at 1
+    pub fn char_for_priority(&self, priority: u8) -> Option<char> {
+        self.alphabet
+            .get(usize::from(priority).checked_sub(1)?)
+            .copied()
+    }
+}
+
+impl Default for Priorities {
+    /// The classic alphabet, `a-z` being worth 1 to 26 and `A-Z` 27 to 52
+    fn default() -> Self {
+        let alphabet = ('a'..='z').chain('A'..='Z').collect::<Vec<char>>();
+        assert_eq!(alphabet.len(), 52);
+        Self::from_alphabet(&alphabet).expect("The classic alphabet holds every item once")
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Container(pub String);
+
+impl Container {
+    /// Builds a container holding each item type of `items` exactly once, however many times
+    /// and wherever it appears
+    fn from_unique_items(items: impl IntoIterator<Item = char>) -> Self {
+        items
+            .into_iter()
+            .collect::<BTreeSet<char>>()
+            .into_iter()
+            .collect::<String>()
+            .into()
+    }
+
+    /// How many times each item type appears in this container
+    pub fn char_counts(&self) -> BTreeMap<char, usize> {
+        self.0.chars().fold(BTreeMap::new(), |mut counts, item| {
+            *counts.entry(item).or_default() += 1;
+            counts
+        })
+    }
+
+    /// Item types found in both containers, each appearing once
+    pub fn intersection(&self, other: &Container) -> Container {
+        Self::from_unique_items(self.0.chars().filter(|item| other.0.contains(*item)))
+    }
+
+    /// Item types found in either container, each appearing once
+    pub fn union(&self, other: &Container) -> Container {
+        Self::from_unique_items(self.0.chars().chain(other.0.chars()))
+    }
+
+    pub fn cumulated_priorities(&self, priorities: &Priorities) -> Result<u64> {
+        self.0
+            .chars()
+            .map(|c| priorities.priority_for_char(c).map(u64::from))
+            .sum()
+    }
+
+    /// Item types present in this container, as a set where bit `n` stands for priority `n + 1`.
+    /// Errors on items whose priority is above 64, see `Priorities::fits_bitmask`.
+    pub fn to_bitmask(&self, priorities: &Priorities) -> Result<u64> {
+        items_bitmask(self.0.chars(), priorities)
+    }
+}
+
+fn items_bitmask(mut items: impl Iterator<Item = char>, priorities: &Priorities) -> Result<u64> {
+    items.try_fold(0u64, |mask, c| {
+        let priority = priorities.priority_for_char(c)?;
+        if u32::from(priority) > u64::BITS {
+            return Err(Day3Error::PriorityExceedsBitmask { item: c, priority });
+        }
+        Ok(mask | 1 << (priority - 1))
+    })
+}
+
+/// Sums the priorities of the item types set in a bitmask made by `Container::to_bitmask`
+pub fn bitmask_cumulated_priorities(mut mask: u64) -> u64 {
+    let mut sum = 0;
+    while mask != 0 {
+        sum += u64::from(mask.trailing_zeros()) + 1;
+        mask &= mask - 1;
+    }
+    sum
+}
+
+impl From<&str> for Container {
+    fn from(s: &str) -> Self {
+        Self(s.to_string())
+    }
+}
+
+impl From<String> for Container {
+    fn from(s: String) -> Self {
+        Self(s)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rucksack {
+    compartments: Vec<Container>,
+}
+
+impl Rucksack {
+    /// Borrowing counterpart of `from_str`, splitting an ASCII `line` into its two compartments
+    /// without allocating
+    pub fn parse_bytes(line: &[u8]) -> Result<RucksackRef<'_>> {
+        if let Some(&item) = line.iter().find(|b| !b.is_ascii_alphabetic()) {
+            return Err(Day3Error::InvalidItem {
+                rucksack: String::from_utf8_lossy(line).into_owned(),
+                item: char::from(item),
+            });
+        }
+        if !line.len().is_multiple_of(2) {
+            return Err(Day3Error::UnevenRucksack {
+                items: line.len(),
+                compartments: 2,
+            });
+        }
+
+        let (first, second) = line.split_at(line.len() / 2);
+        Ok(RucksackRef { first, second })
+    }
+
+    /// Splits `s` into the two usual compartments, holding the same number of items
+    pub fn new_from_str(s: String) -> Result<Self> {
+        // Items are counted as chars rather than bytes to support non-ASCII alphabets
+        let items_count = s.chars().count();
+        if !items_count.is_multiple_of(2) {
+            return Err(Day3Error::UnevenRucksack {
+                items: items_count,
+                compartments: 2,
+            });
+        }
+
+        let midpoint = s
+            .char_indices()
+            .nth(items_count / 2)
+            .map_or(s.len(), |(idx, _)| idx);
+        Self::split_at(&s, midpoint)
+    }
+
+    /// Splits `s` into two compartments, the first one holding its `index` first bytes
+    pub fn split_at(s: &str, index: usize) -> Result<Self> {
+        if index > s.len() {
+            return Err(Day3Error::SplitOutOfRange {
+                rucksack: s.to_owned(),
+                index,
+                len: s.len(),
+            });
+        }
+        let Some((first, second)) = s.split_at_checked(index) else {
+            return Err(Day3Error::SplitInsideItem {
+                rucksack: s.to_owned(),
+                index,
+            });
+        };
+
+        Ok(Self {
+            compartments: vec![first.into(), second.into()],
+        })
+    }
+
+    /// Splits `s` into `count` compartments holding the same number of items
+    pub fn with_compartments(s: &str, count: usize) -> Result<Self> {
+        if count == 0 {
+            return Err(Day3Error::NoCompartments);
+        }
+
+        // Items are counted as chars rather than bytes to support non-ASCII alphabets
+        let items_count = s.chars().count();
+        if !items_count.is_multiple_of(count) {
+            return Err(Day3Error::UnevenRucksack {
+                items: items_count,
+                compartments: count,
+            });
+        }
+
+        let compartment_len = items_count / count;
+        let mut compartments = Vec::with_capacity(count);
+        let mut rest = s;
+        for _ in 0..count {
+            let end = rest
+                .char_indices()
+                .nth(compartment_len)
+                .map_or(rest.len(), |(idx, _)| idx);
+            let (compartment, remaining) = rest.split_at(end);
+            compartments.push(compartment.into());
+            rest = remaining;
+        }
+
+        Ok(Self { compartments })
+    }
+
+    pub fn compartments(&self) -> &[Container] {
+        &self.compartments
+    }
+
+    /// Items found in every compartment
+    pub fn common_items(&self) -> Container {
+        let (first, others) = self
+            .compartments
+            .split_first()
+            .expect("Rucksacks always have at least one compartment");
+        let others: Vec<BTreeMap<char, usize>> =
+            others.iter().map(Container::char_counts).collect();
+        // Counts are keyed by item type, so that duplicates only show up once
+        first
+            .char_counts()
+            .into_keys()
+            .filter(|item| others.iter().all(|counts| counts.contains_key(item)))
+            .collect::<String>()
+            .into()
+    }
+
+    /// Item types shared by the first two compartments, along with how many times each of them
+    /// appears in the first and in the second compartment
+    pub fn misplaced_items(&self) -> Vec<(char, usize, usize)> {
+        let [first, second, ..] = &self.compartments[..] else {
+            return Vec::new();
+        };
+        let second = second.char_counts();
+        first
+            .char_counts()
+            .into_iter()
+            .filter_map(|(item, in_first)| Some((item, in_first, *second.get(&item)?)))
+            .collect()
+    }
+
+    /// Every item type of this rucksack, whatever its compartment
+    fn items(&self) -> Container {
+        self.compartments
+            .iter()
+            .fold(Container::default(), |items, compartment| {
+                items.union(compartment)
+            })
+    }
+
+    /// Same as `common_items`, but returns `None` when both compartments share nothing
+    pub fn checked_common_items(&self) -> Option<Container> {
+        Some(self.common_items()).filter(|common| !common.0.is_empty())
+    }
+
+    /// Same as `common_items` but as a bitmask, see `Container::to_bitmask`
+    pub fn common_items_bitmask(&self, priorities: &Priorities) -> Result<u64> {
+        self.compartments
+            .iter()
+            .try_fold(u64::MAX, |mask, compartment| {
+                Ok(mask & compartment.to_bitmask(priorities)?)
+            })
+    }
+
+    fn items_bitmask(&self, priorities: &Priorities) -> Result<u64> {
+        self.compartments.iter().try_fold(0, |mask, compartment| {
+            Ok(mask | compartment.to_bitmask(priorities)?)
+        })
+    }
+
+    /// Same as `common_items_with_group` but as a bitmask, see `Container::to_bitmask`
+    pub fn common_items_with_group_bitmask(
+        &self,
+        two: &Self,
+        three: &Self,
+        priorities: &Priorities,
+    ) -> Result<u64> {
+        Ok(self.items_bitmask(priorities)?
+            & two.items_bitmask(priorities)?
+            & three.items_bitmask(priorities)?)
+    }
+
+    pub fn common_items_with_group(&self, two: &Self, three: &Self) -> Container {
+        Self::common_items_across(&[self, two, three])
+    }
+
+    /// Items found in every one of `rucksacks`, whatever their compartment.
+    /// A single rucksack shares all of its items with itself, while an empty slice shares none.
+    pub fn common_items_across(rucksacks: &[&Rucksack]) -> Container {
+        rucksacks
+            .iter()
+            .map(|rucksack| rucksack.items())
+            .reduce(|common, items| common.intersection(&items))
+            .unwrap_or_default()
+    }
+}
+
+/// A rucksack borrowing its items from the line it was parsed from, see `Rucksack::parse_bytes`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RucksackRef<'a> {
+    first: &'a [u8],
+    second: &'a [u8],
+}
+
+impl<'a> RucksackRef<'a> {
+    pub fn compartments(&self) -> (&'a [u8], &'a [u8]) {
+        (self.first, self.second)
+    }
+
+    /// Same as `Rucksack::common_items_bitmask`
+    pub fn common_items_bitmask(&self, priorities: &Priorities) -> Result<u64> {
+        let mask = |items: &[u8]| items_bitmask(items.iter().copied().map(char::from), priorities);
+        Ok(mask(self.first)? & mask(self.second)?)
+    }
+
+    /// Sum of the priorities of the items found in both compartments
+    pub fn common_items_priority(&self, priorities: &Priorities) -> Result<u64> {
+        self.common_items_bitmask(priorities)
+            .map(bitmask_cumulated_priorities)
+    }
+
+    pub fn to_rucksack(&self) -> Rucksack {
+        Rucksack {
+            compartments: vec![
+                Container(String::from_utf8_lossy(self.first).into_owned()),
+                Container(String::from_utf8_lossy(self.second).into_owned()),
+            ],
+        }
+    }
+}
+
+impl std::fmt::Display for Rucksack {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.compartments
+            .iter()
+            .try_for_each(|compartment| f.write_str(&compartment.0))
+    }
+}
+
+impl std::str::FromStr for Rucksack {
+    type Err = Day3Error;
+
+    /// Unlike `new_from_str`, also makes sure that every item is an ASCII letter
+    fn from_str(s: &str) -> Result<Self> {
+        if let Some(item) = s.chars().find(|c| !c.is_ascii_alphabetic()) {
+            return Err(Day3Error::InvalidItem {
+                rucksack: s.to_owned(),
+                item,
+            });
+        }
+
+        Self::new_from_str(s.to_string())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Rucksack {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Rucksack {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RucksackGroup(pub Vec<Rucksack>);
+
+impl RucksackGroup {
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn iter(&self) -> std::slice::Iter<'_, Rucksack> {
+        self.0.iter()
+    }
+
+    /// Parses every file of `paths`, in order, into a single group
+    pub fn from_paths(paths: &[std::path::PathBuf]) -> Result<Self> {
+        let mut rucksacks = vec![];
+        for path in paths {
+            let contents =
+                std::fs::read_to_string(path).map_err(|source| Day3Error::UnreadableFile {
+                    path: path.clone(),
+                    source,
+                })?;
+            let group: Self = contents.parse().map_err(|source| Day3Error::InvalidFile {
+                path: path.clone(),
+                source: Box::new(source),
+            })?;
+            rucksacks.extend(group.0);
+        }
+
+        Ok(Self(rucksacks))
+    }
+
+    /// Renders the group back into the puzzle input format, one rucksack per line
+    pub fn to_input_string(&self) -> String {
+        self.0
+            .iter()
+            .map(|rucksack| format!("{rucksack}\n"))
+            .collect()
+    }
+
+    /// Sums the priorities of the items shared by each rucksack's compartments, in parallel
+    /// when the `rayon` feature is enabled
+    pub fn cumulated_priority_sum(&self, priorities: &Priorities) -> Result<u64> {
+        #[cfg(not(feature = "rayon"))]
+        let rucksacks = self.0.iter();
+        #[cfg(feature = "rayon")]
+        let rucksacks = self.0.par_iter();
+
+        rucksacks
+            .map(|rs| {
+                if priorities.fits_bitmask() {
+                    rs.common_items_bitmask(priorities)
+                        .map(bitmask_cumulated_priorities)
+                } else {
+                    rs.common_items().cumulated_priorities(priorities)
+                }
+            })
+            .sum()
+    }
+
+    /// Same as `cumulated_priority_sum`, but errors on rucksacks whose compartments share nothing.
+    /// With the `rayon` feature, which of several such rucksacks gets reported is unspecified.
+    pub fn cumulated_priority_sum_strict(&self, priorities: &Priorities) -> Result<u64> {
+        #[cfg(not(feature = "rayon"))]
+        let rucksacks = self.0.iter().enumerate();
+        #[cfg(feature = "rayon")]
+        let rucksacks = self.0.par_iter().enumerate();
+
+        rucksacks
+            .map(|(idx, rs)| {
+                let no_common_item = || Day3Error::NoCommonItem {
+                    index: idx + 1,
+                    rucksack: rs.to_string(),
+                };
+                if priorities.fits_bitmask() {
+                    match rs.common_items_bitmask(priorities)? {
+                        0 => Err(no_common_item()),
+                        mask => Ok(bitmask_cumulated_priorities(mask)),
+                    }
+                } else {
+                    rs.checked_common_items()
+                        .ok_or_else(no_common_item)?
+                        .cumulated_priorities(priorities)
+                }
+            })
+            .sum()
+    }
+
+    /// Items of the alphabet found in none of the rucksacks, in priority order. Worked out from
+    /// the union of every rucksack's bitmask when the alphabet fits in one.
+    pub fn missing_item_types(&self, priorities: &Priorities) -> Result<Vec<char>> {
+        let missing: Vec<char> = if priorities.fits_bitmask() {
+            let present = self
+                .0
+                .iter()
+                .try_fold(0u64, |mask, rs| Ok(mask | rs.items_bitmask(priorities)?))?;
+            priorities
+                .alphabet
+                .iter()
+                .enumerate()
+                .filter(|(pos, _)| present & 1 << pos == 0)
+                .map(|(_, item)| *item)
+                .collect()
+        } else {
+            let present = self
+                .0
+                .iter()
+                .fold(Container::default(), |items, rs| items.union(&rs.items()));
+            priorities
+                .alphabet
+                .iter()
+                .copied()
+                .filter(|item| !present.0.contains(*item))
+                .collect()
+        };
+        Ok(missing)
+    }
+
+    /// Each rucksack's shared item along with its priority, in order. Rucksacks sharing more than
+    /// one item only report the first of them.
+    pub fn per_rucksack_report(&self, priorities: &Priorities) -> Result<Vec<(char, u8)>> {
+        self.0
+            .iter()
+            .enumerate()
+            .map(|(idx, rs)| {
+                let item =
+                    rs.common_items()
+                        .0
+                        .chars()
+                        .next()
+                        .ok_or_else(|| Day3Error::NoCommonItem {
+                            index: idx + 1,
+                            rucksack: rs.to_string(),
+                        })?;
+                Ok((item, priorities.priority_for_char(item)?))
+            })
+            .collect()
+    }
+
+    pub fn group_badge_priority_sum(&self, priorities: &Priorities) -> Result<u64> {
+        self.badge_priority_sum_with_group_size(3, priorities)
+    }
+
+    /// Sums the priorities of the items shared by every rucksack of each group of `size`
+    /// consecutive rucksacks, the groups being summed in parallel with the `rayon` feature
+    pub fn badge_priority_sum_with_group_size(
+        &self,
+        size: usize,
+        priorities: &Priorities,
+    ) -> Result<u64> {
+        if size == 0 {
+            return Err(Day3Error::EmptyGroup);
+        }
+
+        let leftover = self.len() % size;
+        if leftover != 0 {
+            return Err(Day3Error::IncompleteGroup {
+                rucksacks: self.len(),
+                size,
+                leftover,
+            });
+        }
+
+        #[cfg(not(feature = "rayon"))]
+        let groups = self.0.chunks_exact(size);
+        #[cfg(feature = "rayon")]
+        let groups = self.0.par_chunks_exact(size);
+
+        groups
+            .map(|rucksacks| {
+                if priorities.fits_bitmask() {
+                    rucksacks
+                        .iter()
+                        .try_fold(u64::MAX, |mask, rucksack| {
+                            Ok(mask & rucksack.items_bitmask(priorities)?)
+                        })
+                        .map(bitmask_cumulated_priorities)
+                } else {
+                    let rucksacks: Vec<&Rucksack> = rucksacks.iter().collect();
+                    Rucksack::common_items_across(&rucksacks).cumulated_priorities(priorities)
+                }
+            })
+            .sum()
+    }
+}
+
+impl std::str::FromStr for RucksackGroup {
+    type Err = Day3Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        s.lines()
+            .enumerate()
+            .filter(|(_, line)| !line.is_empty())
+            .map(|(idx, line)| {
+                line.parse::<Rucksack>()
+                    .map_err(|source| Day3Error::InvalidRucksackLine {
+                        line: idx + 1,
+                        source: Box::new(source),
+                    })
+            })
+            .collect()
+    }
+}
+
+impl<'a> IntoIterator for &'a RucksackGroup {
+    type Item = &'a Rucksack;
+    type IntoIter = std::slice::Iter<'a, Rucksack>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl FromIterator<Rucksack> for RucksackGroup {
+    fn from_iter<I: IntoIterator<Item = Rucksack>>(rucksacks: I) -> Self {
+        Self(rucksacks.into_iter().collect())
+    }
+}
+
+/// Day 3 puzzle: the misplaced items' priorities, then the group badges' priorities
+#[cfg(feature = "eyre")]
+#[derive(Debug)]
+pub struct Day3;
+
+#[cfg(feature = "eyre")]
+impl aoc_core::Solution for Day3 {
+    type Input = RucksackGroup;
+
+    #[cfg_attr(
+        feature = "trace",
+        tracing::instrument(level = "debug", skip_all, err, fields(lines = input.lines().count()))
+    )]
+    fn parse(input: &str) -> color_eyre::eyre::Result<Self::Input> {
+        Ok(input.parse()?)
+    }
+
+    #[cfg_attr(
+        feature = "trace",
+        tracing::instrument(level = "debug", skip_all, ret, err)
+    )]
+    fn part1(rucksacks: &Self::Input) -> color_eyre::eyre::Result<String> {
+        Ok(rucksacks
+            .cumulated_priority_sum_strict(&Priorities::default())?
+            .to_string())
+    }
+
+    #[cfg_attr(
+        feature = "trace",
+        tracing::instrument(level = "debug", skip_all, ret, err)
+    )]
+    fn part2(rucksacks: &Self::Input) -> color_eyre::eyre::Result<String> {
+        Ok(rucksacks
+            .group_badge_priority_sum(&Priorities::default())?
+            .to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn conforms_to_brief_step1() {
+        let priorities = Priorities::default();
+        let expected_results = [
+            ("p", 16u64),
+            ("L", 38),
+            ("P", 42),
+            ("v", 22),
+            ("t", 20),
+            ("s", 19),
+        ];
+
+        let rucksacks = RucksackGroup(vec![
+            Rucksack::new_from_str("vJrwpWtwJgWrhcsFMMfFFhFp".into()).unwrap(),
+            Rucksack::new_from_str("jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL".into()).unwrap(),
+            Rucksack::new_from_str("PmmdzqPrVvPwwTWBwg".into()).unwrap(),
+            Rucksack::new_from_str("wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn".into()).unwrap(),
+            Rucksack::new_from_str("ttgJtRGJQctTZtZT".into()).unwrap(),
+            Rucksack::new_from_str("CrZsJsPPZsGzwwsLwLmpwMDw".into()).unwrap(),
+        ]);
+
+        for (rucksack, (expected_str, expected_priority)) in
+            rucksacks.0.iter().zip(expected_results.iter())
+        {
+            let common = rucksack.common_items();
+            assert_eq!(common.0, *expected_str);
+            assert_eq!(
+                common.cumulated_priorities(&priorities).unwrap(),
+                *expected_priority
+            );
+        }
+
+        assert_eq!(rucksacks.cumulated_priority_sum(&priorities).unwrap(), 157);
+    }
+
+    #[test]
+    fn common_items_counts_non_adjacent_duplicates_once() {
+        let priorities = Priorities::default();
+        // `a` and `b` are both shared, `a` appearing twice in the first compartment around `b`
+        let rucksack = Rucksack::new_from_str("abXabYab".into()).unwrap();
+
+        let common = rucksack.common_items();
+        assert_eq!(common.0, "ab");
+        assert_eq!(common.cumulated_priorities(&priorities).unwrap(), 3);
+    }
+
+    #[test]
+    fn char_counts() {
+        let counts = Container::from("aabbc").char_counts();
+        assert_eq!(
+            counts.into_iter().collect::<Vec<_>>(),
+            [('a', 2), ('b', 2), ('c', 1)]
+        );
+        assert!(Container::default().char_counts().is_empty());
+    }
+
+    #[test]
+    fn misplaced_items_are_counted_per_compartment() {
+        // `a` appears twice in the first compartment, `b` twice in the second
+        let rucksack = Rucksack::new_from_str("abXabYab".into()).unwrap();
+        assert_eq!(rucksack.misplaced_items(), [('a', 2, 1), ('b', 1, 2)]);
+
+        let rucksack = Rucksack::new_from_str("vJrwpWtwJgWrhcsFMMfFFhFp".into()).unwrap();
+        assert_eq!(rucksack.misplaced_items(), [('p', 1, 1)]);
+
+        let rucksack = Rucksack::new_from_str("abcd".into()).unwrap();
+        assert!(rucksack.misplaced_items().is_empty());
+    }
+
+    #[test]
+    fn split_at_arbitrary_index() {
+        let rucksack = Rucksack::split_at("abcab", 2).unwrap();
+        assert_eq!(
+            rucksack.compartments(),
+            [Container::from("ab"), "cab".into()]
+        );
+        assert_eq!(rucksack.common_items().0, "ab");
+
+        let rucksack = Rucksack::split_at("abc", 3).unwrap();
+        assert_eq!(rucksack.compartments(), [Container::from("abc"), "".into()]);
+
+        assert!(matches!(
+            Rucksack::split_at("abc", 4),
+            Err(Day3Error::SplitOutOfRange {
+                index: 4,
+                len: 3,
+                ..
+            })
+        ));
+        // `é` takes up bytes 1 and 2
+        assert!(matches!(
+            Rucksack::split_at("aéb", 2),
+            Err(Day3Error::SplitInsideItem { index: 2, .. })
+        ));
+        assert!(Rucksack::split_at("aéb", 3).is_ok());
+
+        assert!(matches!(
+            Rucksack::new_from_str("abc".into()),
+            Err(Day3Error::UnevenRucksack {
+                items: 3,
+                compartments: 2
+            })
+        ));
+        assert_eq!(
+            Rucksack::new_from_str("aébé".into()).unwrap(),
+            Rucksack::split_at("aébé", 3).unwrap()
+        );
+    }
+
+    #[test]
+    fn common_items_with_group_counts_non_adjacent_duplicates_once() {
+        let priorities = Priorities::default();
+        // `Z` appears twice in the first rucksack, around `x` which is shared too
+        let one = Rucksack::new_from_str("ZxZq".into()).unwrap();
+        let two = Rucksack::new_from_str("Zxab".into()).unwrap();
+        let three = Rucksack::new_from_str("cdZx".into()).unwrap();
+
+        let common = one.common_items_with_group(&two, &three);
+        assert_eq!(common.0, "Zx");
+        assert_eq!(common.cumulated_priorities(&priorities).unwrap(), 52 + 24);
+    }
+
+    #[test]
+    fn priority_for_char() {
+        let priorities = Priorities::default();
+        assert_eq!(priorities.priority_for_char('a').unwrap(), 1);
+        assert_eq!(priorities.priority_for_char('p').unwrap(), 16);
+        assert_eq!(priorities.priority_for_char('z').unwrap(), 26);
+        assert_eq!(priorities.priority_for_char('A').unwrap(), 27);
+        assert_eq!(priorities.priority_for_char('L').unwrap(), 38);
+        assert_eq!(priorities.priority_for_char('Z').unwrap(), 52);
+        assert!(matches!(
+            priorities.priority_for_char('1'),
+            Err(Day3Error::UnknownItem('1'))
+        ));
+        assert!(priorities.priority_for_char('é').is_err());
+    }
+
+    #[test]
+    fn missing_item_types() {
+        let rucksacks: RucksackGroup = "abcdefghijklmn\nopqrstuvwxyz\n".parse().unwrap();
+        let missing = rucksacks
+            .missing_item_types(&Priorities::default())
+            .unwrap();
+        assert_eq!(missing, ('A'..='Z').collect::<Vec<_>>());
+
+        let rucksacks: RucksackGroup = BRIEF_INPUT.parse().unwrap();
+        let missing = rucksacks
+            .missing_item_types(&Priorities::default())
+            .unwrap();
+        assert_eq!(missing.into_iter().collect::<String>(), "aeiklouxyAEIKOUXY");
+
+        // Too many item types for a bitmask, falling back to the chars
+        let alphabet: Vec<char> = ('a'..='z')
+            .chain('A'..='Z')
+            .chain('0'..='9')
+            .chain("αβγ".chars())
+            .collect();
+        let priorities = Priorities::from_alphabet(&alphabet).unwrap();
+        assert!(!priorities.fits_bitmask());
+        let rucksacks: RucksackGroup = ["αβαβ", "aγ9a"]
+            .into_iter()
+            .map(|line| Rucksack::new_from_str(line.into()).unwrap())
+            .collect();
+        let missing = rucksacks.missing_item_types(&priorities).unwrap();
+        assert_eq!(missing.len(), alphabet.len() - 5);
+        assert!(!missing.iter().any(|item| "aαβγ9".contains(*item)));
+    }
+
+    #[test]
+    fn to_input_string_roundtrip() {
+        let rucksacks: RucksackGroup = BRIEF_INPUT.parse().unwrap();
+        let input = rucksacks.to_input_string();
+        assert_eq!(input, BRIEF_INPUT);
+
+        let roundtripped: RucksackGroup = input.parse().unwrap();
+        assert_eq!(roundtripped.len(), 6);
+        assert_eq!(
+            roundtripped
+                .cumulated_priority_sum(&Priorities::default())
+                .unwrap(),
+            157
+        );
+    }
+
+    #[test]
+    fn per_rucksack_report() {
+        let rucksack_group: RucksackGroup = BRIEF_INPUT.parse().unwrap();
+        let priorities = Priorities::default();
+        assert_eq!(
+            rucksack_group.per_rucksack_report(&priorities).unwrap(),
+            vec![
+                ('p', 16),
+                ('L', 38),
+                ('P', 42),
+                ('v', 22),
+                ('t', 20),
+                ('s', 19)
+            ]
+        );
+
+        let rucksack_group: RucksackGroup = "abcd\nabad".parse().unwrap();
+        assert!(matches!(
+            rucksack_group.per_rucksack_report(&priorities),
+            Err(Day3Error::NoCommonItem { index: 1, .. })
+        ));
+    }
+
+    #[test]
+    fn borrowed_rucksacks_match_owned_ones() {
+        let priorities = Priorities::default();
+        for line in BRIEF_INPUT.lines() {
+            let borrowed = Rucksack::parse_bytes(line.as_bytes()).unwrap();
+            let owned: Rucksack = line.parse().unwrap();
+            assert_eq!(
+                borrowed.common_items_priority(&priorities).unwrap(),
+                owned
+                    .common_items()
+                    .cumulated_priorities(&priorities)
+                    .unwrap()
+            );
+            assert_eq!(
+                borrowed.common_items_bitmask(&priorities).unwrap(),
+                owned.common_items_bitmask(&priorities).unwrap()
+            );
+            assert_eq!(borrowed.to_rucksack(), owned);
+        }
+
+        let (first, second) = Rucksack::parse_bytes(b"abXabY").unwrap().compartments();
+        assert_eq!((first, second), (&b"abX"[..], &b"abY"[..]));
+        assert!(matches!(
+            Rucksack::parse_bytes(b"abc"),
+            Err(Day3Error::UnevenRucksack { items: 3, .. })
+        ));
+        assert!(matches!(
+            Rucksack::parse_bytes(b"ab1b"),
+            Err(Day3Error::InvalidItem { item: '1', .. })
+        ));
+    }
+
+    #[test]
+    fn char_for_priority() {
+        let priorities = Priorities::default();
+        assert_eq!(priorities.char_for_priority(16), Some('p'));
+        assert_eq!(priorities.char_for_priority(1), Some('a'));
+        assert_eq!(priorities.char_for_priority(52), Some('Z'));
+        assert_eq!(priorities.char_for_priority(0), None);
+        assert_eq!(priorities.char_for_priority(53), None);
+
+        for item in ('a'..='z').chain('A'..='Z') {
+            let priority = priorities.priority_for_char(item).unwrap();
+            assert_eq!(priorities.char_for_priority(priority), Some(item));
+        }
+    }
+
+    #[test]
+    fn invalid_items_are_reported_instead_of_panicking() {
+        let priorities = Priorities::default();
+        let rucksacks = RucksackGroup(vec![
+            Rucksack::new_from_str("vJrwpWtwJgWrhcsFMMfFFhFp".into()).unwrap(),
+            Rucksack::new_from_str("a1b1".into()).unwrap(),
+            Rucksack::new_from_str("PmmdzqPrVvPwwTWBwg".into()).unwrap(),
+        ]);
+
+        assert!(rucksacks.0[1]
+            .common_items()
+            .cumulated_priorities(&priorities)
+            .is_err());
+        assert!(rucksacks.cumulated_priority_sum(&priorities).is_err());
+
+        let group = RucksackGroup(vec![
+            Rucksack::new_from_str("a1b1".into()).unwrap(),
+            Rucksack::new_from_str("c1d1".into()).unwrap(),
+            Rucksack::new_from_str("e1f1".into()).unwrap(),
+        ]);
+        assert!(group.group_badge_priority_sum(&priorities).is_err());
+    }
+
+    #[test]
+    fn bitmask_intersections_match_char_based_ones() {
+        let priorities = Priorities::default();
+        let rucksacks = [
+            "vJrwpWtwJgWrhcsFMMfFFhFp",
+            "jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL",
+            "PmmdzqPrVvPwwTWBwg",
+            "wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn",
+            "ttgJtRGJQctTZtZT",
+            "CrZsJsPPZsGzwwsLwLmpwMDw",
+        ]
+        .map(|s| Rucksack::new_from_str(s.into()).unwrap());
+
+        assert_eq!(
+            Container::from("p").to_bitmask(&priorities).unwrap(),
+            1 << 15
+        );
+        for rucksack in &rucksacks {
+            let common = rucksack.common_items();
+            let mask = rucksack.common_items_bitmask(&priorities).unwrap();
+            assert_eq!(common.to_bitmask(&priorities).unwrap(), mask);
+            assert_eq!(
+                bitmask_cumulated_priorities(mask),
+                common.cumulated_priorities(&priorities).unwrap()
+            );
+        }
+
+        for group in rucksacks.chunks_exact(3) {
+            let common = group[0].common_items_with_group(&group[1], &group[2]);
+            let mask = group[0]
+                .common_items_with_group_bitmask(&group[1], &group[2], &priorities)
+                .unwrap();
+            assert_eq!(common.to_bitmask(&priorities).unwrap(), mask);
+        }
+
+        let group = RucksackGroup(rucksacks.to_vec());
+        assert_eq!(group.group_badge_priority_sum(&priorities).unwrap(), 70);
+    }
+
+    #[test]
+    fn group_badge_priority_sum_rejects_incomplete_groups() {
+        let priorities = Priorities::default();
+        let rucksacks = RucksackGroup(vec![
+            Rucksack::new_from_str("vJrwpWtwJgWrhcsFMMfFFhFp".into()).unwrap(),
+            Rucksack::new_from_str("jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL".into()).unwrap(),
+            Rucksack::new_from_str("PmmdzqPrVvPwwTWBwg".into()).unwrap(),
+            Rucksack::new_from_str("wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn".into()).unwrap(),
+        ]);
+
+        let err = rucksacks.group_badge_priority_sum(&priorities).unwrap_err();
+        assert!(matches!(
+            err,
+            Day3Error::IncompleteGroup {
+                rucksacks: 4,
+                size: 3,
+                leftover: 1
+            }
+        ));
+        assert!(err.to_string().contains("1 would be left over"));
+    }
+
+    #[test]
+    fn badge_priority_sum_with_group_size() {
+        let priorities = Priorities::default();
+        let rucksacks = RucksackGroup(vec![
+            Rucksack::new_from_str("abcd".into()).unwrap(),
+            Rucksack::new_from_str("cxyz".into()).unwrap(),
+            Rucksack::new_from_str("ABCD".into()).unwrap(),
+            Rucksack::new_from_str("DEFA".into()).unwrap(),
+        ]);
+
+        // `c` (3) is shared by the first pair, `A` (27) and `D` (30) by the second one
+        assert_eq!(
+            rucksacks
+                .badge_priority_sum_with_group_size(2, &priorities)
+                .unwrap(),
+            3 + 27 + 30
+        );
+        assert_eq!(
+            rucksacks
+                .badge_priority_sum_with_group_size(4, &priorities)
+                .unwrap(),
+            0
+        );
+        assert!(matches!(
+            rucksacks.badge_priority_sum_with_group_size(0, &priorities),
+            Err(Day3Error::EmptyGroup)
+        ));
+        assert!(rucksacks
+            .badge_priority_sum_with_group_size(3, &priorities)
+            .is_err());
+    }
+
+    #[test]
+    fn common_items_across() {
+        let rucksacks = ["aZbcdXef", "gXhZijak", "ZlmnoXap", "qXrsatZu", "XvwaZxyz"]
+            .map(|s| Rucksack::new_from_str(s.into()).unwrap());
+        let refs: Vec<&Rucksack> = rucksacks.iter().collect();
+
+        assert_eq!(Rucksack::common_items_across(&refs).0, "XZa");
+        assert_eq!(Rucksack::common_items_across(&refs[..1]).0, "XZabcdef");
+        assert_eq!(Rucksack::common_items_across(&[]).0, "");
+    }
+
+    #[test]
+    fn no_common_item_is_detected() {
+        let priorities = Priorities::default();
+        let rucksack = Rucksack::new_from_str("abcdefgh".into()).unwrap();
+        assert_eq!(rucksack.common_items().0, "");
+        assert!(rucksack.checked_common_items().is_none());
+
+        let rucksacks = RucksackGroup(vec![
+            Rucksack::new_from_str("vJrwpWtwJgWrhcsFMMfFFhFp".into()).unwrap(),
+            rucksack,
+        ]);
+        assert_eq!(rucksacks.cumulated_priority_sum(&priorities).unwrap(), 16);
+        let err = rucksacks
+            .cumulated_priority_sum_strict(&priorities)
+            .unwrap_err();
+        assert!(matches!(err, Day3Error::NoCommonItem { index: 2, .. }));
+        assert!(err.to_string().contains("Rucksack #2 (abcdefgh)"));
+    }
+
+    #[test]
+    fn rucksack_from_str_validates_contents() {
+        let rucksack: Rucksack = "vJrwpWtwJgWrhcsFMMfFFhFp".parse().unwrap();
+        assert_eq!(
+            rucksack.compartments(),
+            &["vJrwpWtwJgWr".into(), "hcsFMMfFFhFp".into()]
+        );
+
+        assert!(matches!(
+            "abc".parse::<Rucksack>(),
+            Err(Day3Error::UnevenRucksack {
+                items: 3,
+                compartments: 2
+            })
+        ));
+
+        let err = "ab1d".parse::<Rucksack>().unwrap_err();
+        assert!(matches!(err, Day3Error::InvalidItem { item: '1', .. }));
+        assert!(err.to_string().contains("`1`"));
+        assert!("abcé".parse::<Rucksack>().is_err());
+    }
+
+    const BRIEF_INPUT: &str = "vJrwpWtwJgWrhcsFMMfFFhFp
+jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL
+PmmdzqPrVvPwwTWBwg
+wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn
+ttgJtRGJQctTZtZT
+CrZsJsPPZsGzwwsLwLmpwMDw
+";
+
+    #[test]
+    fn rucksack_group_from_str() {
+        let priorities = Priorities::default();
+        let rucksacks: RucksackGroup = BRIEF_INPUT.parse().unwrap();
+        assert_eq!(rucksacks.len(), 6);
+        assert_eq!(rucksacks.cumulated_priority_sum(&priorities).unwrap(), 157);
+        assert_eq!(rucksacks.group_badge_priority_sum(&priorities).unwrap(), 70);
+
+        let err = "abcd\n\nab1d\n".parse::<RucksackGroup>().unwrap_err();
+        assert!(matches!(
+            &err,
+            Day3Error::InvalidRucksackLine { line: 3, source }
+                if matches!(**source, Day3Error::InvalidItem { item: '1', .. })
+        ));
+        assert_eq!(err.to_string(), "Invalid rucksack on line 3");
+    }
+
+    #[test]
+    fn priority_sums_without_bitmask() {
+        // Digits and a few symbols push the alphabet past what fits in a bitmask
+        let alphabet: Vec<char> = ('a'..='z')
+            .chain('A'..='Z')
+            .chain('0'..='9')
+            .chain(['!', '?', '#'])
+            .collect();
+        let priorities = Priorities::from_alphabet(&alphabet).unwrap();
+        assert!(!priorities.fits_bitmask());
+
+        let rucksacks: RucksackGroup = BRIEF_INPUT.parse().unwrap();
+        assert_eq!(rucksacks.cumulated_priority_sum(&priorities).unwrap(), 157);
+        assert_eq!(
+            rucksacks
+                .cumulated_priority_sum_strict(&priorities)
+                .unwrap(),
+            157
+        );
+        assert_eq!(rucksacks.group_badge_priority_sum(&priorities).unwrap(), 70);
+    }
+
+    #[test]
+    fn rucksack_group_from_iterator() {
+        let rucksack_group: RucksackGroup = [
+            "vJrwpWtwJgWrhcsFMMfFFhFp",
+            "PmmdzqPrVvPwwTWBwg",
+            "ttgJtRGJQctTZtZT",
+        ]
+        .into_iter()
+        .map(|line| line.parse::<Rucksack>().unwrap())
+        .collect();
+        assert_eq!(rucksack_group.len(), 3);
+        assert!(!rucksack_group.is_empty());
+        assert_eq!(
+            rucksack_group.iter().next().unwrap().to_string(),
+            "vJrwpWtwJgWrhcsFMMfFFhFp"
+        );
+        assert!(RucksackGroup::default().is_empty());
+    }
+
+    #[test]
+    fn from_paths_merges_files() {
+        let dir = std::env::temp_dir();
+        let first = dir.join(format!("day3-from-paths-{}-1.txt", std::process::id()));
+        let second = dir.join(format!("day3-from-paths-{}-2.txt", std::process::id()));
+        let lines: Vec<&str> = BRIEF_INPUT.lines().collect();
+        std::fs::write(&first, lines[..3].join("\n")).unwrap();
+        std::fs::write(&second, lines[3..].join("\n")).unwrap();
+
+        let priorities = Priorities::default();
+        let merged = RucksackGroup::from_paths(&[first.clone(), second.clone()]).unwrap();
+        let concatenated: RucksackGroup = BRIEF_INPUT.parse().unwrap();
+        assert_eq!(merged.len(), concatenated.len());
+        assert_eq!(
+            merged.cumulated_priority_sum(&priorities).unwrap(),
+            concatenated.cumulated_priority_sum(&priorities).unwrap()
+        );
+        assert_eq!(merged.group_badge_priority_sum(&priorities).unwrap(), 70);
+
+        let missing = dir.join(format!(
+            "day3-from-paths-{}-missing.txt",
+            std::process::id()
+        ));
+        let err = RucksackGroup::from_paths(&[first.clone(), missing.clone()]).unwrap_err();
+        assert!(matches!(err, Day3Error::UnreadableFile { ref path, .. } if *path == missing));
+
+        std::fs::write(&second, "abc\n").unwrap();
+        let err = RucksackGroup::from_paths(&[first.clone(), second.clone()]).unwrap_err();
+        assert!(matches!(err, Day3Error::InvalidFile { ref path, .. } if *path == second));
+
+        std::fs::remove_file(first).unwrap();
+        std::fs::remove_file(second).unwrap();
+    }
+
+    #[test]
+    fn container_set_operations() {
+        let abc = Container::from("abcabc");
+        let def = Container::from("fed");
+        let cdc = Container::from("cdc");
+
+        // Disjoint
+        assert_eq!(abc.intersection(&def), Container::default());
+        assert_eq!(abc.union(&def), Container::from("abcdef"));
+
+        // Overlapping
+        assert_eq!(abc.intersection(&cdc), Container::from("c"));
+        assert_eq!(cdc.intersection(&def), Container::from("d"));
+        assert_eq!(abc.union(&cdc), Container::from("abcd"));
+
+        // Identical
+        assert_eq!(abc.intersection(&abc), Container::from("abc"));
+        assert_eq!(abc.union(&abc), Container::from("abc"));
+    }
+
+    #[test]
+    fn priorities_from_alphabet() {
+        let priorities = Priorities::from_alphabet(&['x', '@', 'β']).unwrap();
+        assert_eq!(priorities.len(), 3);
+        assert_eq!(priorities.priority_for_char('x').unwrap(), 1);
+        assert_eq!(priorities.priority_for_char('@').unwrap(), 2);
+        assert_eq!(priorities.priority_for_char('β').unwrap(), 3);
+        assert!(priorities.priority_for_char('a').is_err());
+
+        let rucksacks = RucksackGroup(vec![
+            Rucksack::new_from_str("x@@β".into()).unwrap(),
+            Rucksack::new_from_str("ββxβ".into()).unwrap(),
+        ]);
+        assert_eq!(
+            rucksacks.cumulated_priority_sum(&priorities).unwrap(),
+            2 + 3
+        );
+
+        assert!(matches!(
+            Priorities::from_alphabet(&['x', '@', 'x']),
+            Err(Day3Error::DuplicatePriority {
+                item: 'x',
+                first: 1,
+                second: 3
+            })
+        ));
+    }
+
+    #[test]
+    fn priorities_beyond_bitmask_capacity() {
+        let alphabet: Vec<char> = ('a'..='z')
+            .chain('A'..='Z')
+            .chain('0'..='9')
+            .chain("+-*/".chars())
+            .collect();
+        let priorities = Priorities::from_alphabet(&alphabet).unwrap();
+        assert_eq!(priorities.len(), 66);
+        assert!(!priorities.fits_bitmask());
+
+        // `/` is worth 66, which the bitmask cannot hold
+        assert!(matches!(
+            Container::from("/").to_bitmask(&priorities),
+            Err(Day3Error::PriorityExceedsBitmask {
+                item: '/',
+                priority: 66
+            })
+        ));
+        let rucksacks = RucksackGroup(vec![
+            Rucksack::new_from_str("a/b/".into()).unwrap(),
+            Rucksack::new_from_str("/cd/".into()).unwrap(),
+            Rucksack::new_from_str("ef/g".into()).unwrap(),
+        ]);
+        assert_eq!(
+            rucksacks.cumulated_priority_sum(&priorities).unwrap(),
+            66 * 2
+        );
+        assert_eq!(rucksacks.group_badge_priority_sum(&priorities).unwrap(), 66);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_json_roundtrip() {
+        let priorities = Priorities::default();
+        let rucksacks: RucksackGroup = BRIEF_INPUT.parse().unwrap();
+        let json = serde_json::to_string(&rucksacks).unwrap();
+        assert!(json.starts_with(r#"["vJrwpWtwJgWrhcsFMMfFFhFp","#));
+
+        let roundtripped: RucksackGroup = serde_json::from_str(&json).unwrap();
+        assert_eq!(roundtripped.0, rucksacks.0);
+        assert_eq!(
+            roundtripped.cumulated_priority_sum(&priorities).unwrap(),
+            157
+        );
+
+        assert!(serde_json::from_str::<RucksackGroup>(r#"["abc"]"#).is_err());
+    }
+
+    #[test]
+    fn rucksack_display() {
+        let rucksack: Rucksack = "vJrwpWtwJgWrhcsFMMfFFhFp".parse().unwrap();
+        assert_eq!(
+            rucksack.to_string(),
+            format!(
+                "{}{}",
+                rucksack.compartments[0].0, rucksack.compartments[1].0
+            )
+        );
+        assert_eq!(format!("{rucksack}"), "vJrwpWtwJgWrhcsFMMfFFhFp");
+    }
+
+    #[test]
+    fn rucksack_with_three_compartments() {
+        let priorities = Priorities::default();
+        let rucksack = Rucksack::with_compartments("abXcdXXef", 3).unwrap();
+        assert_eq!(
+            rucksack.compartments(),
+            &["abX".into(), "cdX".into(), "Xef".into()]
+        );
+        assert_eq!(rucksack.to_string(), "abXcdXXef");
+
+        assert_eq!(rucksack.common_items().0, "X");
+        assert_eq!(
+            rucksack.common_items_bitmask(&priorities).unwrap(),
+            Container::from("X").to_bitmask(&priorities).unwrap()
+        );
+
+        assert!(Rucksack::with_compartments("abXcdXXe", 3).is_err());
+        assert!(matches!(
+            Rucksack::with_compartments("abXcdXXef", 0),
+            Err(Day3Error::NoCompartments)
+        ));
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    mod prop {
+        use super::*;
+        use proptest::prelude::*;
+
+        /// ASCII letters, shrinking towards `a`
+        fn any_item() -> impl Strategy<Value = char> {
+            (0u8..52).prop_map(|idx| match idx {
+                0..=25 => (b'a' + idx) as char,
+                _ => (b'A' + idx - 26) as char,
+            })
+        }
+
+        /// Even-length lines, built from pairs of items so that shrinking keeps them even
+        fn any_rucksack_line() -> impl Strategy<Value = String> {
+            proptest::collection::vec((any_item(), any_item()), 1..64).prop_map(|pairs| {
+                pairs
+                    .into_iter()
+                    .flat_map(|(first, second)| [first, second])
+                    .collect()
+            })
+        }
+
+        proptest! {
+            #[test]
+            fn compartments_recombine_to_line(line in any_rucksack_line()) {
+                let rucksack = Rucksack::new_from_str(line.clone()).unwrap();
+                prop_assert_eq!(rucksack.compartments().len(), 2);
+                prop_assert_eq!(rucksack.compartments()[0].0.len(), line.len() / 2);
+                prop_assert_eq!(rucksack.to_string(), line);
+            }
+        }
+    }
+}
@@ -0,0 +1,36 @@
+use crate::{Priorities, RucksackGroup};
+use wasm_bindgen::prelude::*;
+
+/// Sum of the priorities of the items misplaced in each rucksack of `input`
+#[wasm_bindgen]
+pub fn solve_part1(input: &str) -> Result<u64, JsError> {
+    let rucksacks: RucksackGroup = input.parse()?;
+    Ok(rucksacks.cumulated_priority_sum_strict(&Priorities::default())?)
+}
+
+/// Sum of the priorities of the badges of each group of 3 rucksacks of `input`
+#[wasm_bindgen]
+pub fn solve_part2(input: &str) -> Result<u64, JsError> {
+    let rucksacks: RucksackGroup = input.parse()?;
+    Ok(rucksacks.group_badge_priority_sum(&Priorities::default())?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wasm_bindgen_test::wasm_bindgen_test;
+
+    const BRIEF_INPUT: &str = "vJrwpWtwJgWrhcsFMMfFFhFp
+jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL
+PmmdzqPrVvPwwTWBwg
+wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn
+ttgJtRGJQctTZtZT
+CrZsJsPPZsGzwwsLwLmpwMDw
+";
+
+    #[wasm_bindgen_test]
+    fn conforms_to_brief() {
+        assert_eq!(solve_part1(BRIEF_INPUT).unwrap(), 157);
+        assert_eq!(solve_part2(BRIEF_INPUT).unwrap(), 70);
+    }
+}
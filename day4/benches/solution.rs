@@ -0,0 +1,17 @@
+use aoc_core::Solution;
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use day4::Day4;
+
+/// Brief example repeated, so that the benches don't need the real puzzle input
+const FIXTURE: &str = include_str!("fixture.txt");
+
+fn solution(c: &mut Criterion) {
+    let input = Day4::parse(FIXTURE).unwrap();
+
+    c.bench_function("day4 parse", |b| b.iter(|| Day4::parse(black_box(FIXTURE))));
+    c.bench_function("day4 part1", |b| b.iter(|| Day4::part1(black_box(&input))));
+    c.bench_function("day4 part2", |b| b.iter(|| Day4::part2(black_box(&input))));
+}
+
+criterion_group!(benches, solution);
+criterion_main!(benches);
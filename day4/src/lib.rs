@@ -0,0 +1,230 @@
+#[derive(Debug, thiserror::Error)]
+pub enum Day4Error {
+    #[error("Section range `{0}` should look like `2-4`")]
+    MissingDash(String),
+    #[error("Assignment pair `{0}` should look like `2-4,6-8`")]
+    MissingComma(String),
+    #[error("Invalid section id `{value}`")]
+    InvalidSectionId {
+        value: String,
+        #[source]
+        source: std::num::ParseIntError,
+    },
+    #[error("Section range {start}-{end} ends before it starts")]
+    ReversedRange { start: u32, end: u32 },
+    #[error("Invalid assignment pair on line {line}")]
+    InvalidLine {
+        line: usize,
+        #[source]
+        source: Box<Day4Error>,
+    },
+}
+
+pub type Result<T, E = Day4Error> = std::result::Result<T, E>;
+
+/// Sections assigned to an elf, both ends included
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SectionRange {
+    pub start: u32,
+    pub end: u32,
+}
+
+impl SectionRange {
+    pub fn new(start: u32, end: u32) -> Result<Self> {
+        if start > end {
+            return Err(Day4Error::ReversedRange { start, end });
+        }
+        Ok(Self { start, end })
+    }
+
+    pub fn fully_contains(&self, other: &Self) -> bool {
+        self.start <= other.start && other.end <= self.end
+    }
+
+    pub fn overlaps(&self, other: &Self) -> bool {
+        self.start <= other.end && other.start <= self.end
+    }
+}
+
+impl std::str::FromStr for SectionRange {
+    type Err = Day4Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let (start, end) = s
+            .split_once('-')
+            .ok_or_else(|| Day4Error::MissingDash(s.to_owned()))?;
+        let section_id = |value: &str| {
+            value
+                .trim()
+                .parse()
+                .map_err(|source| Day4Error::InvalidSectionId {
+                    value: value.to_owned(),
+                    source,
+                })
+        };
+        Self::new(section_id(start)?, section_id(end)?)
+    }
+}
+
+/// The section ranges of a pair of elves
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AssignmentPair(pub SectionRange, pub SectionRange);
+
+impl AssignmentPair {
+    pub fn one_fully_contains_other(&self) -> bool {
+        self.0.fully_contains(&self.1) || self.1.fully_contains(&self.0)
+    }
+
+    pub fn overlaps(&self) -> bool {
+        self.0.overlaps(&self.1)
+    }
+}
+
+impl std::str::FromStr for AssignmentPair {
+    type Err = Day4Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let (first, second) = s
+            .split_once(',')
+            .ok_or_else(|| Day4Error::MissingComma(s.to_owned()))?;
+        Ok(Self(first.parse()?, second.parse()?))
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct Assignments(pub Vec<AssignmentPair>);
+
+impl Assignments {
+    /// Pairs in which one elf's sections include all of the other's
+    pub fn fully_containing_count(&self) -> usize {
+        self.0
+            .iter()
+            .filter(|pair| pair.one_fully_contains_other())
+            .count()
+    }
+
+    /// Pairs sharing at least one section
+    pub fn overlapping_count(&self) -> usize {
+        self.0.iter().filter(|pair| pair.overlaps()).count()
+    }
+}
+
+impl std::str::FromStr for Assignments {
+    type Err = Day4Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        s.lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty())
+            .map(|(idx, line)| {
+                line.trim()
+                    .parse()
+                    .map_err(|source| Day4Error::InvalidLine {
+                        line: idx + 1,
+                        source: Box::new(source),
+                    })
+            })
+            .collect::<Result<Vec<AssignmentPair>>>()
+            .map(Self)
+    }
+}
+
+/// Day 4 puzzle: pairs where one range fully contains the other, then pairs overlapping at all
+#[cfg(feature = "eyre")]
+#[derive(Debug)]
+pub struct Day4;
+
+#[cfg(feature = "eyre")]
+impl aoc_core::Solution for Day4 {
+    type Input = Assignments;
+
+    #[cfg_attr(
+        feature = "trace",
+        tracing::instrument(level = "debug", skip_all, err, fields(lines = input.lines().count()))
+    )]
+    fn parse(input: &str) -> color_eyre::eyre::Result<Self::Input> {
+        Ok(input.parse()?)
+    }
+
+    #[cfg_attr(
+        feature = "trace",
+        tracing::instrument(level = "debug", skip_all, ret, err)
+    )]
+    fn part1(assignments: &Self::Input) -> color_eyre::eyre::Result<String> {
+        Ok(assignments.fully_containing_count().to_string())
+    }
+
+    #[cfg_attr(
+        feature = "trace",
+        tracing::instrument(level = "debug", skip_all, ret, err)
+    )]
+    fn part2(assignments: &Self::Input) -> color_eyre::eyre::Result<String> {
+        Ok(assignments.overlapping_count().to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BRIEF_INPUT: &str = "2-4,6-8
+2-3,4-5
+5-7,7-9
+2-8,3-7
+6-6,4-6
+2-6,4-8
+";
+
+    #[test]
+    fn conforms_to_brief_step1() {
+        let assignments: Assignments = BRIEF_INPUT.parse().unwrap();
+        assert_eq!(assignments.0.len(), 6);
+        assert_eq!(assignments.fully_containing_count(), 2);
+    }
+
+    #[test]
+    fn conforms_to_brief_step2() {
+        let assignments: Assignments = BRIEF_INPUT.parse().unwrap();
+        assert_eq!(assignments.overlapping_count(), 4);
+    }
+
+    #[test]
+    fn section_range_relations() {
+        let range = SectionRange::new(2, 8).unwrap();
+        assert!(range.fully_contains(&SectionRange::new(3, 7).unwrap()));
+        assert!(range.fully_contains(&range));
+        assert!(!SectionRange::new(3, 7).unwrap().fully_contains(&range));
+
+        // Ranges sharing a single bound section overlap
+        assert!(SectionRange::new(5, 7)
+            .unwrap()
+            .overlaps(&SectionRange::new(7, 9).unwrap()));
+        assert!(!SectionRange::new(2, 3)
+            .unwrap()
+            .overlaps(&SectionRange::new(4, 5).unwrap()));
+    }
+
+    #[test]
+    fn rejects_malformed_assignments() {
+        assert!(matches!(
+            "2-4;6-8".parse::<AssignmentPair>(),
+            Err(Day4Error::MissingComma(_))
+        ));
+        assert!(matches!(
+            "24,6-8".parse::<AssignmentPair>(),
+            Err(Day4Error::MissingDash(_))
+        ));
+        assert!(matches!(
+            "2-x,6-8".parse::<AssignmentPair>(),
+            Err(Day4Error::InvalidSectionId { .. })
+        ));
+        assert!(matches!(
+            "4-2,6-8".parse::<AssignmentPair>(),
+            Err(Day4Error::ReversedRange { start: 4, end: 2 })
+        ));
+        assert!(matches!(
+            "2-4,6-8\n\n1-2".parse::<Assignments>(),
+            Err(Day4Error::InvalidLine { line: 3, .. })
+        ));
+    }
+}
@@ -0,0 +1,24 @@
+use aoc_core::Solution as _;
+use color_eyre::eyre::Result;
+use day4::Day4;
+
+fn main() -> Result<()> {
+    #[cfg(feature = "trace")]
+    aoc_core::init_tracing();
+    color_eyre::install()?;
+
+    // Reads the puzzle input from the given path, or from stdin
+    let input = match std::env::args().nth(1) {
+        Some(path) if path != "-" => aoc_core::read_to_string(path)?,
+        _ => std::io::read_to_string(std::io::stdin().lock())?,
+    };
+    let assignments = Day4::parse(&input)?;
+
+    println!(
+        "Step1: Pairs fully containing the other: {}",
+        Day4::part1(&assignments)?
+    );
+    println!("Step2: Overlapping pairs: {}", Day4::part2(&assignments)?);
+
+    Ok(())
+}
@@ -0,0 +1,17 @@
+use aoc_core::Solution;
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use day5::Day5;
+
+/// Brief drawing followed by back and forth moves, so that the benches don't need the real puzzle input
+const FIXTURE: &str = include_str!("fixture.txt");
+
+fn solution(c: &mut Criterion) {
+    let input = Day5::parse(FIXTURE).unwrap();
+
+    c.bench_function("day5 parse", |b| b.iter(|| Day5::parse(black_box(FIXTURE))));
+    c.bench_function("day5 part1", |b| b.iter(|| Day5::part1(black_box(&input))));
+    c.bench_function("day5 part2", |b| b.iter(|| Day5::part2(black_box(&input))));
+}
+
+criterion_group!(benches, solution);
+criterion_main!(benches);
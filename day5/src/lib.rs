@@ -0,0 +1,358 @@
+#[derive(Debug, thiserror::Error)]
+pub enum Day5Error {
+    #[error("The drawing should be followed by a blank line, then the rearrangement procedure")]
+    MissingProcedure,
+    #[error("The drawing should end with the stack numbers")]
+    MissingStackNumbers,
+    #[error("Invalid crate `{cell}` on line {line}, crates should look like `[A]`")]
+    InvalidCrate { line: usize, cell: String },
+    #[error("Crate cell {stack} on line {line} splits a character in two")]
+    SplitCharacter {
+        line: usize,
+        stack: usize,
+        #[source]
+        source: std::str::Utf8Error,
+    },
+    #[error("Crate on line {line} sits past the {stacks} numbered stacks")]
+    UnnumberedStack { line: usize, stacks: usize },
+    #[error("Move `{value}` on line {line} should look like `move 1 from 2 to 3`")]
+    MalformedMove { line: usize, value: String },
+    #[error("Invalid number `{value}` on line {line}")]
+    InvalidNumber {
+        line: usize,
+        value: String,
+        #[source]
+        source: std::num::ParseIntError,
+    },
+    #[error("There is no stack {stack}, stacks go from 1 to {stacks}")]
+    UnknownStack { stack: usize, stacks: usize },
+    #[error("Cannot move {requested} crates off stack {stack}, which has {available}")]
+    NotEnoughCrates {
+        stack: usize,
+        requested: usize,
+        available: usize,
+    },
+}
+
+pub type Result<T, E = Day5Error> = std::result::Result<T, E>;
+
+/// A single step of the rearrangement procedure, stacks being numbered from 1
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Move {
+    pub count: usize,
+    pub from: usize,
+    pub to: usize,
+}
+
+impl Move {
+    fn parse(line: usize, value: &str) -> Result<Self> {
+        let number = |n: &str| {
+            n.parse().map_err(|source| Day5Error::InvalidNumber {
+                line,
+                value: n.to_owned(),
+                source,
+            })
+        };
+
+        match value.split_whitespace().collect::<Vec<_>>()[..] {
+            ["move", count, "from", from, "to", to] => Ok(Self {
+                count: number(count)?,
+                from: number(from)?,
+                to: number(to)?,
+            }),
+            _ => Err(Day5Error::MalformedMove {
+                line,
+                value: value.to_owned(),
+            }),
+        }
+    }
+}
+
+/// Stacks of crates, each one listed from bottom to top
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Stacks(pub Vec<Vec<char>>);
+
+impl Stacks {
+    /// Parses the drawing, the line numbers being used for error reporting only
+    fn from_drawing(drawing: &[(usize, &str)]) -> Result<Self> {
+        let ((_, numbers), crates) = drawing.split_last().ok_or(Day5Error::MissingStackNumbers)?;
+        let stacks = numbers.split_whitespace().count();
+        if stacks == 0 || numbers.contains('[') {
+            return Err(Day5Error::MissingStackNumbers);
+        }
+
+        let numbered = stacks;
+        let mut stacks = vec![vec![]; numbered];
+        // Crates are drawn top first, but the stacks are built from the bottom up
+        for &(line, row) in crates.iter().rev() {
+            let row = row.trim_end();
+            for (idx, cell) in row.as_bytes().chunks(4).enumerate() {
+                // Cells are 4 bytes wide, which can cut through a multi-byte character
+                let cell = std::str::from_utf8(cell)
+                    .map_err(|source| Day5Error::SplitCharacter {
+                        line,
+                        stack: idx + 1,
+                        source,
+                    })?
+                    .trim_end();
+                match cell.as_bytes() {
+                    [] => continue,
+                    [b'[', item, b']'] if item.is_ascii_alphabetic() => stacks
+                        .get_mut(idx)
+                        .ok_or(Day5Error::UnnumberedStack {
+                            line,
+                            stacks: numbered,
+                        })?
+                        .push(*item as char),
+                    _ => {
+                        return Err(Day5Error::InvalidCrate {
+                            line,
+                            cell: cell.to_owned(),
+                        })
+                    }
+                }
+            }
+        }
+
+        Ok(Self(stacks))
+    }
+
+    fn check_move(&self, mv: &Move) -> Result<()> {
+        let stacks = self.0.len();
+        for stack in [mv.from, mv.to] {
+            if !(1..=stacks).contains(&stack) {
+                return Err(Day5Error::UnknownStack { stack, stacks });
+            }
+        }
+
+        let available = self.0[mv.from - 1].len();
+        if mv.count > available {
+            return Err(Day5Error::NotEnoughCrates {
+                stack: mv.from,
+                requested: mv.count,
+                available,
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Moves crates one at a time, like the CrateMover 9000 does, reversing their order
+    pub fn apply_9000(&mut self, mv: &Move) -> Result<()> {
+        self.check_move(mv)?;
+        for _ in 0..mv.count {
+            // Cannot fail, the move having been checked above
+            if let Some(item) = self.0[mv.from - 1].pop() {
+                self.0[mv.to - 1].push(item);
+            }
+        }
+        Ok(())
+    }
+
+    /// Moves crates all at once, like the CrateMover 9001 does, preserving their order
+    pub fn apply_9001(&mut self, mv: &Move) -> Result<()> {
+        self.check_move(mv)?;
+        let from = &mut self.0[mv.from - 1];
+        let moved = from.split_off(from.len() - mv.count);
+        self.0[mv.to - 1].extend(moved);
+        Ok(())
+    }
+
+    /// Crates on top of each stack, empty stacks being skipped
+    pub fn tops(&self) -> String {
+        self.0.iter().filter_map(|stack| stack.last()).collect()
+    }
+}
+
+/// The starting stacks, along with the rearrangement procedure to apply to them
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Procedure {
+    pub stacks: Stacks,
+    pub moves: Vec<Move>,
+}
+
+impl Procedure {
+    /// Top crates once rearranged with the CrateMover 9000
+    pub fn run_9000(&self) -> Result<String> {
+        self.run(Stacks::apply_9000)
+    }
+
+    /// Top crates once rearranged with the CrateMover 9001
+    pub fn run_9001(&self) -> Result<String> {
+        self.run(Stacks::apply_9001)
+    }
+
+    fn run(&self, apply: impl Fn(&mut Stacks, &Move) -> Result<()>) -> Result<String> {
+        let mut stacks = self.stacks.clone();
+        for mv in &self.moves {
+            apply(&mut stacks, mv)?;
+        }
+        Ok(stacks.tops())
+    }
+}
+
+impl std::str::FromStr for Procedure {
+    type Err = Day5Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let mut sections = aoc_core::groups(s.lines().map(Ok::<_, std::convert::Infallible>))
+            .map(|group| group.unwrap_or_else(|never| match never {}));
+        let drawing = sections.next().ok_or(Day5Error::MissingStackNumbers)?;
+        let stacks = Stacks::from_drawing(&drawing)?;
+
+        // Trailing blank lines make for empty sections
+        let moves = sections
+            .flatten()
+            .map(|(line, value)| Move::parse(line, value))
+            .collect::<Result<Vec<_>>>()?;
+        if moves.is_empty() {
+            return Err(Day5Error::MissingProcedure);
+        }
+
+        Ok(Self { stacks, moves })
+    }
+}
+
+/// Day 5 puzzle: top crates after rearranging with the CrateMover 9000, then the 9001
+#[cfg(feature = "eyre")]
+#[derive(Debug)]
+pub struct Day5;
+
+#[cfg(feature = "eyre")]
+impl aoc_core::Solution for Day5 {
+    type Input = Procedure;
+
+    #[cfg_attr(
+        feature = "trace",
+        tracing::instrument(level = "debug", skip_all, err, fields(lines = input.lines().count()))
+    )]
+    fn parse(input: &str) -> color_eyre::eyre::Result<Self::Input> {
+        Ok(input.parse()?)
+    }
+
+    #[cfg_attr(
+        feature = "trace",
+        tracing::instrument(level = "debug", skip_all, ret, err)
+    )]
+    fn part1(procedure: &Self::Input) -> color_eyre::eyre::Result<String> {
+        Ok(procedure.run_9000()?)
+    }
+
+    #[cfg_attr(
+        feature = "trace",
+        tracing::instrument(level = "debug", skip_all, ret, err)
+    )]
+    fn part2(procedure: &Self::Input) -> color_eyre::eyre::Result<String> {
+        Ok(procedure.run_9001()?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BRIEF_INPUT: &str = "    [D]
+[N] [C]
+[Z] [M] [P]
+ 1   2   3
+
+move 1 from 2 to 1
+move 3 from 1 to 3
+move 2 from 2 to 1
+move 1 from 1 to 2
+";
+
+    #[test]
+    fn parses_brief_drawing() {
+        let procedure: Procedure = BRIEF_INPUT.parse().unwrap();
+        assert_eq!(
+            procedure.stacks,
+            Stacks(vec![vec!['Z', 'N'], vec!['M', 'C', 'D'], vec!['P']])
+        );
+        assert_eq!(procedure.moves.len(), 4);
+        assert_eq!(
+            procedure.moves[1],
+            Move {
+                count: 3,
+                from: 1,
+                to: 3
+            }
+        );
+    }
+
+    #[test]
+    fn conforms_to_brief_step1() {
+        let procedure: Procedure = BRIEF_INPUT.parse().unwrap();
+        assert_eq!(procedure.run_9000().unwrap(), "CMZ");
+    }
+
+    #[test]
+    fn conforms_to_brief_step2() {
+        let procedure: Procedure = BRIEF_INPUT.parse().unwrap();
+        assert_eq!(procedure.run_9001().unwrap(), "MCD");
+    }
+
+    #[test]
+    fn rejects_impossible_moves() {
+        let mut stacks = Stacks(vec![vec!['A'], vec![]]);
+        let mv = |count, from, to| Move { count, from, to };
+
+        assert!(matches!(
+            stacks.apply_9000(&mv(1, 3, 1)),
+            Err(Day5Error::UnknownStack {
+                stack: 3,
+                stacks: 2
+            })
+        ));
+        assert!(matches!(
+            stacks.apply_9001(&mv(1, 1, 0)),
+            Err(Day5Error::UnknownStack { stack: 0, .. })
+        ));
+        assert!(matches!(
+            stacks.apply_9001(&mv(2, 1, 2)),
+            Err(Day5Error::NotEnoughCrates {
+                stack: 1,
+                requested: 2,
+                available: 1
+            })
+        ));
+        // Failed moves leave the stacks untouched
+        assert_eq!(stacks, Stacks(vec![vec!['A'], vec![]]));
+    }
+
+    #[test]
+    fn rejects_malformed_input() {
+        assert!(matches!(
+            "[A]\n 1 ".parse::<Procedure>(),
+            Err(Day5Error::MissingProcedure)
+        ));
+        assert!(matches!(
+            "[A]\n\nmove 1 from 1 to 1".parse::<Procedure>(),
+            Err(Day5Error::MissingStackNumbers)
+        ));
+        assert!(matches!(
+            "[A] (B)\n 1   2 \n\nmove 1 from 1 to 2".parse::<Procedure>(),
+            Err(Day5Error::InvalidCrate { line: 1, .. })
+        ));
+        assert!(matches!(
+            "[A]    é\n 1   2 \n\nmove 1 from 1 to 2".parse::<Procedure>(),
+            Err(Day5Error::SplitCharacter {
+                line: 1,
+                stack: 2,
+                ..
+            })
+        ));
+        assert!(matches!(
+            "[A] [B]\n 1 \n\nmove 1 from 1 to 2".parse::<Procedure>(),
+            Err(Day5Error::UnnumberedStack { line: 1, stacks: 1 })
+        ));
+        assert!(matches!(
+            "[A]\n 1 \n\nmove 1 to 1".parse::<Procedure>(),
+            Err(Day5Error::MalformedMove { line: 4, .. })
+        ));
+        assert!(matches!(
+            "[A]\n 1 \n\nmove x from 1 to 1".parse::<Procedure>(),
+            Err(Day5Error::InvalidNumber { line: 4, .. })
+        ));
+    }
+}
@@ -0,0 +1,27 @@
+use aoc_core::Solution as _;
+use color_eyre::eyre::Result;
+use day5::Day5;
+
+fn main() -> Result<()> {
+    #[cfg(feature = "trace")]
+    aoc_core::init_tracing();
+    color_eyre::install()?;
+
+    // Reads the puzzle input from the given path, or from stdin
+    let input = match std::env::args().nth(1) {
+        Some(path) if path != "-" => aoc_core::read_to_string(path)?,
+        _ => std::io::read_to_string(std::io::stdin().lock())?,
+    };
+    let procedure = Day5::parse(&input)?;
+
+    println!(
+        "Step1: Top crates with the CrateMover 9000: {}",
+        Day5::part1(&procedure)?
+    );
+    println!(
+        "Step2: Top crates with the CrateMover 9001: {}",
+        Day5::part2(&procedure)?
+    );
+
+    Ok(())
+}
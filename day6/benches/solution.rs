@@ -0,0 +1,17 @@
+use aoc_core::Solution;
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use day6::Day6;
+
+/// Brief example behind a long marker-less stretch, so that the benches don't need the real puzzle input
+const FIXTURE: &str = include_str!("fixture.txt");
+
+fn solution(c: &mut Criterion) {
+    let input = Day6::parse(FIXTURE).unwrap();
+
+    c.bench_function("day6 parse", |b| b.iter(|| Day6::parse(black_box(FIXTURE))));
+    c.bench_function("day6 part1", |b| b.iter(|| Day6::part1(black_box(&input))));
+    c.bench_function("day6 part2", |b| b.iter(|| Day6::part2(black_box(&input))));
+}
+
+criterion_group!(benches, solution);
+criterion_main!(benches);
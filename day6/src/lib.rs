@@ -0,0 +1,190 @@
+#[derive(Debug, thiserror::Error)]
+pub enum Day6Error {
+    #[error(
+        "Invalid character `{found}` at position {position}, signals are made of lowercase letters"
+    )]
+    InvalidSignal { position: usize, found: char },
+    #[error("No run of {window} distinct characters in the signal")]
+    NoMarker { window: usize },
+}
+
+pub type Result<T, E = Day6Error> = std::result::Result<T, E>;
+
+/// Window size of start-of-packet markers
+pub const PACKET_WINDOW: usize = 4;
+/// Window size of start-of-message markers
+pub const MESSAGE_WINDOW: usize = 14;
+
+/// Number of bytes read by the end of the first run of `window` distinct bytes
+///
+/// Signals are expected to be lowercase ASCII letters, but any byte is told apart from the
+/// others, each of them owning a slot of the set tracking the current window's contents.
+pub fn first_marker(input: &str, window: usize) -> Option<usize> {
+    let bytes = input.as_bytes();
+    if window == 0 || window > bytes.len() {
+        return None;
+    }
+
+    // The window only ever holds distinct bytes, shrinking from its start as needed
+    let mut start = 0;
+    let mut in_window = [false; 256];
+    for (end, &b) in bytes.iter().enumerate() {
+        while in_window[usize::from(b)] {
+            in_window[usize::from(bytes[start])] = false;
+            start += 1;
+        }
+        in_window[usize::from(b)] = true;
+        if end + 1 - start == window {
+            return Some(end + 1);
+        }
+    }
+
+    None
+}
+
+/// A datastream received by the communication device
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Signal(String);
+
+impl Signal {
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Characters to read before the first run of `window` distinct characters is complete
+    pub fn marker(&self, window: usize) -> Result<usize> {
+        first_marker(&self.0, window).ok_or(Day6Error::NoMarker { window })
+    }
+}
+
+impl std::str::FromStr for Signal {
+    type Err = Day6Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let s = s.trim();
+        if let Some((position, found)) =
+            s.chars().enumerate().find(|(_, c)| !c.is_ascii_lowercase())
+        {
+            return Err(Day6Error::InvalidSignal { position, found });
+        }
+        Ok(Self(s.to_owned()))
+    }
+}
+
+/// Day 6 puzzle: position of the first start-of-packet marker, then start-of-message marker
+#[cfg(feature = "eyre")]
+#[derive(Debug)]
+pub struct Day6;
+
+#[cfg(feature = "eyre")]
+impl aoc_core::Solution for Day6 {
+    type Input = Signal;
+
+    #[cfg_attr(
+        feature = "trace",
+        tracing::instrument(level = "debug", skip_all, err, fields(len = input.len()))
+    )]
+    fn parse(input: &str) -> color_eyre::eyre::Result<Self::Input> {
+        Ok(input.parse()?)
+    }
+
+    #[cfg_attr(
+        feature = "trace",
+        tracing::instrument(level = "debug", skip_all, ret, err)
+    )]
+    fn part1(signal: &Self::Input) -> color_eyre::eyre::Result<String> {
+        Ok(signal.marker(PACKET_WINDOW)?.to_string())
+    }
+
+    #[cfg_attr(
+        feature = "trace",
+        tracing::instrument(level = "debug", skip_all, ret, err)
+    )]
+    fn part2(signal: &Self::Input) -> color_eyre::eyre::Result<String> {
+        Ok(signal.marker(MESSAGE_WINDOW)?.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Brief signals, along with their start-of-packet and start-of-message markers
+    const BRIEF_SIGNALS: [(&str, usize, usize); 5] = [
+        ("mjqjpqmgbljsphdztnvjfqwrcgsmlb", 7, 19),
+        ("bvwbjplbgvbhsrlpgdmjqwftvncz", 5, 23),
+        ("nppdvjthqldpwncqszvftbrmjlhg", 6, 23),
+        ("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg", 10, 29),
+        ("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw", 11, 26),
+    ];
+
+    #[test]
+    fn conforms_to_brief_step1() {
+        for (signal, packet, _) in BRIEF_SIGNALS {
+            assert_eq!(
+                first_marker(signal, PACKET_WINDOW),
+                Some(packet),
+                "{signal}"
+            );
+        }
+    }
+
+    #[test]
+    fn conforms_to_brief_step2() {
+        for (signal, _, message) in BRIEF_SIGNALS {
+            assert_eq!(
+                first_marker(signal, MESSAGE_WINDOW),
+                Some(message),
+                "{signal}"
+            );
+        }
+    }
+
+    #[test]
+    fn matches_naive_window_scan() {
+        let naive = |input: &str, window: usize| {
+            input
+                .as_bytes()
+                .windows(window)
+                .position(|w| {
+                    w.iter()
+                        .enumerate()
+                        .all(|(idx, b)| !w[idx + 1..].contains(b))
+                })
+                .map(|idx| idx + window)
+        };
+        for (signal, _, _) in BRIEF_SIGNALS {
+            for window in 1..=signal.len() {
+                assert_eq!(
+                    first_marker(signal, window),
+                    naive(signal, window),
+                    "{signal}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn reports_missing_markers() {
+        assert_eq!(first_marker("abc", 4), None);
+        assert_eq!(first_marker("aaaaaa", 2), None);
+        assert_eq!(first_marker("abc", 0), None);
+        // Bytes outside of `a-z` don't collide with letters
+        assert_eq!(first_marker("aA", 2), Some(2));
+        assert_eq!(first_marker("a!A\u{1}a", 4), Some(4));
+
+        let signal: Signal = "abcabc\n".parse().unwrap();
+        assert_eq!(signal.marker(3).unwrap(), 3);
+        assert!(matches!(
+            signal.marker(4),
+            Err(Day6Error::NoMarker { window: 4 })
+        ));
+        assert!(matches!(
+            "abC".parse::<Signal>(),
+            Err(Day6Error::InvalidSignal {
+                position: 2,
+                found: 'C'
+            })
+        ));
+    }
+}
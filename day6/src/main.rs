@@ -0,0 +1,21 @@
+use aoc_core::Solution as _;
+use color_eyre::eyre::Result;
+use day6::Day6;
+
+fn main() -> Result<()> {
+    #[cfg(feature = "trace")]
+    aoc_core::init_tracing();
+    color_eyre::install()?;
+
+    // Reads the puzzle input from the given path, or from stdin
+    let input = match std::env::args().nth(1) {
+        Some(path) if path != "-" => aoc_core::read_to_string(path)?,
+        _ => std::io::read_to_string(std::io::stdin().lock())?,
+    };
+    let signal = Day6::parse(&input)?;
+
+    println!("Step1: Start-of-packet marker: {}", Day6::part1(&signal)?);
+    println!("Step2: Start-of-message marker: {}", Day6::part2(&signal)?);
+
+    Ok(())
+}
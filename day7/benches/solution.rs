@@ -0,0 +1,18 @@
+use aoc_core::Solution;
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use day7::Day7;
+
+/// Brief session replayed in 100 directories, with shrunk file sizes, so that the benches don't
+/// need the real puzzle input
+const FIXTURE: &str = include_str!("fixture.txt");
+
+fn solution(c: &mut Criterion) {
+    let input = Day7::parse(FIXTURE).unwrap();
+
+    c.bench_function("day7 parse", |b| b.iter(|| Day7::parse(black_box(FIXTURE))));
+    c.bench_function("day7 part1", |b| b.iter(|| Day7::part1(black_box(&input))));
+    c.bench_function("day7 part2", |b| b.iter(|| Day7::part2(black_box(&input))));
+}
+
+criterion_group!(benches, solution);
+criterion_main!(benches);
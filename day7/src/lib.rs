@@ -0,0 +1,364 @@
+#[derive(Debug, thiserror::Error)]
+pub enum Day7Error {
+    #[error("Unknown command `{value}` on line {line}")]
+    UnknownCommand { line: usize, value: String },
+    #[error("Line {line} lists a directory entry without any `$ ls` before it")]
+    UnexpectedOutput { line: usize },
+    #[error("Directory entry `{value}` on line {line} should look like `dir a` or `123 b.txt`")]
+    InvalidEntry { line: usize, value: String },
+    #[error("Invalid file size `{value}` on line {line}")]
+    InvalidFileSize {
+        line: usize,
+        value: String,
+        #[source]
+        source: std::num::ParseIntError,
+    },
+    #[error("Line {line} moves out of the root directory")]
+    CdAboveRoot { line: usize },
+    #[error("{used} bytes are used out of the {total} bytes the disk holds")]
+    DiskOverfull { used: u64, total: u64 },
+    #[error("{free} bytes are already free, no directory needs to be deleted")]
+    EnoughFreeSpace { free: u64 },
+}
+
+pub type Result<T, E = Day7Error> = std::result::Result<T, E>;
+
+/// Directories holding at most that many bytes count towards part 1
+pub const SMALL_DIRECTORY_SIZE: u64 = 100_000;
+/// Size of the device's disk
+pub const DISK_SIZE: u64 = 70_000_000;
+/// Free space the update needs
+pub const UPDATE_SIZE: u64 = 30_000_000;
+
+/// Index of a directory in its `Filesystem`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DirId(usize);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Directory {
+    pub name: String,
+    /// `None` for the root directory only
+    pub parent: Option<DirId>,
+    pub children: Vec<DirId>,
+    /// Files directly within the directory, along with their size
+    pub files: Vec<(String, u64)>,
+}
+
+/// Directory tree, stored in an arena where parents come before their children
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Filesystem {
+    dirs: Vec<Directory>,
+}
+
+impl Default for Filesystem {
+    fn default() -> Self {
+        Self {
+            dirs: vec![Directory {
+                name: "/".into(),
+                parent: None,
+                children: vec![],
+                files: vec![],
+            }],
+        }
+    }
+}
+
+impl std::ops::Index<DirId> for Filesystem {
+    type Output = Directory;
+
+    fn index(&self, id: DirId) -> &Directory {
+        &self.dirs[id.0]
+    }
+}
+
+impl Filesystem {
+    pub const ROOT: DirId = DirId(0);
+
+    pub fn len(&self) -> usize {
+        self.dirs.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.dirs.is_empty()
+    }
+
+    /// Child of `parent` named `name`, created if need be
+    pub fn child(&mut self, parent: DirId, name: &str) -> DirId {
+        if let Some(&child) = self[parent]
+            .children
+            .iter()
+            .find(|&&child| self[child].name == name)
+        {
+            return child;
+        }
+
+        let child = DirId(self.dirs.len());
+        self.dirs.push(Directory {
+            name: name.to_owned(),
+            parent: Some(parent),
+            children: vec![],
+            files: vec![],
+        });
+        self.dirs[parent.0].children.push(child);
+        child
+    }
+
+    /// Adds a file to `dir`, files listed again being counted once
+    pub fn add_file(&mut self, dir: DirId, name: &str, size: u64) {
+        let files = &mut self.dirs[dir.0].files;
+        if !files.iter().any(|(file, _)| file == name) {
+            files.push((name.to_owned(), size));
+        }
+    }
+
+    /// Total size of each directory, subdirectories included, indexed like the arena
+    pub fn sizes(&self) -> Vec<u64> {
+        let mut sizes: Vec<u64> = self
+            .dirs
+            .iter()
+            .map(|dir| dir.files.iter().map(|(_, size)| size).sum())
+            .collect();
+        // Children always come after their parent, so they're complete by the time they're added
+        for (idx, dir) in self.dirs.iter().enumerate().rev() {
+            if let Some(parent) = dir.parent {
+                sizes[parent.0] += sizes[idx];
+            }
+        }
+        sizes
+    }
+
+    pub fn used_space(&self) -> u64 {
+        self.sizes()[Self::ROOT.0]
+    }
+
+    /// Sum of the sizes of the directories holding at most `limit` bytes
+    pub fn small_directories_size(&self, limit: u64) -> u64 {
+        self.sizes().into_iter().filter(|&size| size <= limit).sum()
+    }
+
+    /// Size of the smallest directory to delete for `needed` bytes to be free on a `total` disk
+    pub fn smallest_directory_freeing(&self, total: u64, needed: u64) -> Result<u64> {
+        let sizes = self.sizes();
+        let used = sizes[Self::ROOT.0];
+        let free = total
+            .checked_sub(used)
+            .ok_or(Day7Error::DiskOverfull { used, total })?;
+        let missing = needed
+            .checked_sub(free)
+            .filter(|&missing| missing > 0)
+            .ok_or(Day7Error::EnoughFreeSpace { free })?;
+
+        // The root directory is always big enough
+        Ok(sizes
+            .into_iter()
+            .filter(|&size| size >= missing)
+            .min()
+            .unwrap_or(used))
+    }
+}
+
+impl std::str::FromStr for Filesystem {
+    type Err = Day7Error;
+
+    /// Replays a terminal session, starting in the root directory
+    fn from_str(s: &str) -> Result<Self> {
+        let mut fs = Self::default();
+        let mut cwd = Self::ROOT;
+        let mut listing = false;
+
+        for (idx, value) in s.lines().enumerate() {
+            let line = idx + 1;
+            let value = value.trim();
+            if value.is_empty() {
+                continue;
+            }
+
+            if let Some(command) = value.strip_prefix("$ ") {
+                listing = false;
+                match command.split_whitespace().collect::<Vec<_>>()[..] {
+                    ["ls"] => listing = true,
+                    ["cd", "/"] => cwd = Self::ROOT,
+                    ["cd", ".."] => cwd = fs[cwd].parent.ok_or(Day7Error::CdAboveRoot { line })?,
+                    ["cd", name] => cwd = fs.child(cwd, name),
+                    _ => {
+                        return Err(Day7Error::UnknownCommand {
+                            line,
+                            value: command.to_owned(),
+                        })
+                    }
+                }
+                continue;
+            }
+
+            if !listing {
+                return Err(Day7Error::UnexpectedOutput { line });
+            }
+            match value.split_once(' ') {
+                Some(("dir", name)) => {
+                    fs.child(cwd, name);
+                }
+                Some((size, name)) => {
+                    let size = size.parse().map_err(|source| Day7Error::InvalidFileSize {
+                        line,
+                        value: size.to_owned(),
+                        source,
+                    })?;
+                    fs.add_file(cwd, name, size);
+                }
+                None => {
+                    return Err(Day7Error::InvalidEntry {
+                        line,
+                        value: value.to_owned(),
+                    })
+                }
+            }
+        }
+
+        Ok(fs)
+    }
+}
+
+/// Day 7 puzzle: total size of the small directories, then of the directory to delete
+#[cfg(feature = "eyre")]
+#[derive(Debug)]
+pub struct Day7;
+
+#[cfg(feature = "eyre")]
+impl aoc_core::Solution for Day7 {
+    type Input = Filesystem;
+
+    #[cfg_attr(
+        feature = "trace",
+        tracing::instrument(level = "debug", skip_all, err, fields(lines = input.lines().count()))
+    )]
+    fn parse(input: &str) -> color_eyre::eyre::Result<Self::Input> {
+        Ok(input.parse()?)
+    }
+
+    #[cfg_attr(
+        feature = "trace",
+        tracing::instrument(level = "debug", skip_all, ret, err)
+    )]
+    fn part1(fs: &Self::Input) -> color_eyre::eyre::Result<String> {
+        Ok(fs.small_directories_size(SMALL_DIRECTORY_SIZE).to_string())
+    }
+
+    #[cfg_attr(
+        feature = "trace",
+        tracing::instrument(level = "debug", skip_all, ret, err)
+    )]
+    fn part2(fs: &Self::Input) -> color_eyre::eyre::Result<String> {
+        Ok(fs
+            .smallest_directory_freeing(DISK_SIZE, UPDATE_SIZE)?
+            .to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BRIEF_INPUT: &str = "$ cd /
+$ ls
+dir a
+14848514 b.txt
+8504156 c.dat
+dir d
+$ cd a
+$ ls
+dir e
+29116 f
+2557 g
+62596 h.lst
+$ cd e
+$ ls
+584 i
+$ cd ..
+$ cd ..
+$ cd d
+$ ls
+4060174 j
+8033020 d.log
+5626152 d.ext
+7214296 k
+";
+
+    #[test]
+    fn builds_brief_tree() {
+        let fs: Filesystem = BRIEF_INPUT.parse().unwrap();
+        assert_eq!(fs.len(), 4);
+
+        let root = &fs[Filesystem::ROOT];
+        let names: Vec<&str> = root
+            .children
+            .iter()
+            .map(|&id| fs[id].name.as_str())
+            .collect();
+        assert_eq!(names, ["a", "d"]);
+        assert_eq!(root.files.len(), 2);
+        assert_eq!(fs.sizes(), [48381165, 94853, 24933642, 584]);
+    }
+
+    #[test]
+    fn conforms_to_brief_step1() {
+        let fs: Filesystem = BRIEF_INPUT.parse().unwrap();
+        assert_eq!(fs.small_directories_size(SMALL_DIRECTORY_SIZE), 95437);
+    }
+
+    #[test]
+    fn conforms_to_brief_step2() {
+        let fs: Filesystem = BRIEF_INPUT.parse().unwrap();
+        assert_eq!(fs.used_space(), 48381165);
+        assert_eq!(
+            fs.smallest_directory_freeing(DISK_SIZE, UPDATE_SIZE)
+                .unwrap(),
+            24933642
+        );
+    }
+
+    #[test]
+    fn listing_twice_counts_files_once() {
+        let fs: Filesystem = "$ ls\n10 a\ndir b\n$ cd b\n$ cd ..\n$ ls\n10 a\ndir b\n"
+            .parse()
+            .unwrap();
+        assert_eq!(fs.len(), 2);
+        assert_eq!(fs.used_space(), 10);
+    }
+
+    #[test]
+    fn rejects_malformed_sessions() {
+        assert!(matches!(
+            "$ cd /\n$ rm -rf a".parse::<Filesystem>(),
+            Err(Day7Error::UnknownCommand { line: 2, .. })
+        ));
+        assert!(matches!(
+            "$ cd /\n10 a".parse::<Filesystem>(),
+            Err(Day7Error::UnexpectedOutput { line: 2 })
+        ));
+        assert!(matches!(
+            "$ ls\nfile".parse::<Filesystem>(),
+            Err(Day7Error::InvalidEntry { line: 2, .. })
+        ));
+        assert!(matches!(
+            "$ ls\nten a".parse::<Filesystem>(),
+            Err(Day7Error::InvalidFileSize { line: 2, .. })
+        ));
+        assert!(matches!(
+            "$ cd ..".parse::<Filesystem>(),
+            Err(Day7Error::CdAboveRoot { line: 1 })
+        ));
+
+        let fs: Filesystem = "$ ls\n50 a".parse().unwrap();
+        assert!(matches!(
+            fs.smallest_directory_freeing(40, 10),
+            Err(Day7Error::DiskOverfull {
+                used: 50,
+                total: 40
+            })
+        ));
+        assert!(matches!(
+            fs.smallest_directory_freeing(100, 50),
+            Err(Day7Error::EnoughFreeSpace { free: 50 })
+        ));
+    }
+}
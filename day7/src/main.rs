@@ -0,0 +1,27 @@
+use aoc_core::Solution as _;
+use color_eyre::eyre::Result;
+use day7::Day7;
+
+fn main() -> Result<()> {
+    #[cfg(feature = "trace")]
+    aoc_core::init_tracing();
+    color_eyre::install()?;
+
+    // Reads the puzzle input from the given path, or from stdin
+    let input = match std::env::args().nth(1) {
+        Some(path) if path != "-" => aoc_core::read_to_string(path)?,
+        _ => std::io::read_to_string(std::io::stdin().lock())?,
+    };
+    let fs = Day7::parse(&input)?;
+
+    println!(
+        "Step1: Total size of the small directories: {}",
+        Day7::part1(&fs)?
+    );
+    println!(
+        "Step2: Size of the directory to delete: {}",
+        Day7::part2(&fs)?
+    );
+
+    Ok(())
+}
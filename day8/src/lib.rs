@@ -0,0 +1,264 @@
+#[derive(Debug, thiserror::Error)]
+pub enum Day8Error {
+    #[error("Invalid tree height `{found}` on line {line}, column {column}")]
+    InvalidHeight {
+        line: usize,
+        column: usize,
+        found: char,
+    },
+    #[error("Line {line} has {found} trees, while the grid is {expected} trees wide")]
+    RaggedRow {
+        line: usize,
+        expected: usize,
+        found: usize,
+    },
+    #[error("The grid has no trees")]
+    EmptyGrid,
+}
+
+pub type Result<T, E = Day8Error> = std::result::Result<T, E>;
+
+/// Directions to look in from a tree
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl Direction {
+    pub const ALL: [Self; 4] = [Self::Up, Self::Down, Self::Left, Self::Right];
+}
+
+/// What can be seen looking from a tree towards the grid's edge
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Scan {
+    /// Whether no tree as tall or taller stands between the tree and the edge
+    pub visible_from_edge: bool,
+    /// Trees seen before the view gets blocked, the blocking tree included
+    pub viewing_distance: usize,
+}
+
+/// Tree heights, row by row
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TreeGrid(pub Vec<Vec<u8>>);
+
+impl TreeGrid {
+    pub fn rows(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn columns(&self) -> usize {
+        self.0.first().map_or(0, Vec::len)
+    }
+
+    /// Looks from the tree at `row`, `col` in `direction`
+    pub fn scan(&self, row: usize, col: usize, direction: Direction) -> Scan {
+        let height = self.0[row][col];
+        let heights: Box<dyn Iterator<Item = u8>> = match direction {
+            Direction::Up => Box::new((0..row).rev().map(|r| self.0[r][col])),
+            Direction::Down => Box::new((row + 1..self.rows()).map(|r| self.0[r][col])),
+            Direction::Left => Box::new(self.0[row][..col].iter().rev().copied()),
+            Direction::Right => Box::new(self.0[row][col + 1..].iter().copied()),
+        };
+
+        let mut viewing_distance = 0;
+        for other in heights {
+            viewing_distance += 1;
+            if other >= height {
+                return Scan {
+                    visible_from_edge: false,
+                    viewing_distance,
+                };
+            }
+        }
+        Scan {
+            visible_from_edge: true,
+            viewing_distance,
+        }
+    }
+
+    pub fn is_visible(&self, row: usize, col: usize) -> bool {
+        Direction::ALL
+            .into_iter()
+            .any(|direction| self.scan(row, col, direction).visible_from_edge)
+    }
+
+    /// Product of the viewing distances in all four directions
+    pub fn scenic_score(&self, row: usize, col: usize) -> usize {
+        Direction::ALL
+            .into_iter()
+            .map(|direction| self.scan(row, col, direction).viewing_distance)
+            .product()
+    }
+
+    fn positions(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+        (0..self.rows()).flat_map(move |row| (0..self.columns()).map(move |col| (row, col)))
+    }
+
+    /// Trees visible from outside the grid
+    pub fn visible_count(&self) -> usize {
+        self.positions()
+            .filter(|&(row, col)| self.is_visible(row, col))
+            .count()
+    }
+
+    pub fn max_scenic_score(&self) -> usize {
+        self.positions()
+            .map(|(row, col)| self.scenic_score(row, col))
+            .max()
+            .unwrap_or_default()
+    }
+}
+
+impl std::str::FromStr for TreeGrid {
+    type Err = Day8Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let mut grid: Vec<Vec<u8>> = vec![];
+        for (idx, line) in s.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() {
+                continue;
+            }
+
+            let row = line
+                .chars()
+                .enumerate()
+                .map(|(column, c)| {
+                    c.to_digit(10)
+                        .map(|height| height as u8)
+                        .ok_or(Day8Error::InvalidHeight {
+                            line: idx + 1,
+                            column: column + 1,
+                            found: c,
+                        })
+                })
+                .collect::<Result<Vec<_>>>()?;
+            if let Some(expected) = grid.first().map(Vec::len) {
+                if row.len() != expected {
+                    return Err(Day8Error::RaggedRow {
+                        line: idx + 1,
+                        expected,
+                        found: row.len(),
+                    });
+                }
+            }
+            grid.push(row);
+        }
+
+        if grid.is_empty() {
+            return Err(Day8Error::EmptyGrid);
+        }
+        Ok(Self(grid))
+    }
+}
+
+/// Day 8 puzzle: trees visible from outside the grid, then the best scenic score
+#[cfg(feature = "eyre")]
+#[derive(Debug)]
+pub struct Day8;
+
+#[cfg(feature = "eyre")]
+impl aoc_core::Solution for Day8 {
+    type Input = TreeGrid;
+
+    #[cfg_attr(
+        feature = "trace",
+        tracing::instrument(level = "debug", skip_all, err, fields(lines = input.lines().count()))
+    )]
+    fn parse(input: &str) -> color_eyre::eyre::Result<Self::Input> {
+        Ok(input.parse()?)
+    }
+
+    #[cfg_attr(
+        feature = "trace",
+        tracing::instrument(level = "debug", skip_all, ret, err)
+    )]
+    fn part1(grid: &Self::Input) -> color_eyre::eyre::Result<String> {
+        Ok(grid.visible_count().to_string())
+    }
+
+    #[cfg_attr(
+        feature = "trace",
+        tracing::instrument(level = "debug", skip_all, ret, err)
+    )]
+    fn part2(grid: &Self::Input) -> color_eyre::eyre::Result<String> {
+        Ok(grid.max_scenic_score().to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BRIEF_INPUT: &str = "30373
+25512
+65332
+33549
+35390
+";
+
+    #[test]
+    fn conforms_to_brief_step1() {
+        let grid: TreeGrid = BRIEF_INPUT.parse().unwrap();
+        assert_eq!((grid.rows(), grid.columns()), (5, 5));
+        assert_eq!(grid.visible_count(), 21);
+    }
+
+    #[test]
+    fn conforms_to_brief_step2() {
+        let grid: TreeGrid = BRIEF_INPUT.parse().unwrap();
+        assert_eq!(grid.scenic_score(1, 2), 4);
+        assert_eq!(grid.scenic_score(3, 2), 8);
+        assert_eq!(grid.max_scenic_score(), 8);
+    }
+
+    #[test]
+    fn scans_each_direction() {
+        let grid: TreeGrid = BRIEF_INPUT.parse().unwrap();
+        let scan = |row, col, direction| {
+            let Scan {
+                visible_from_edge,
+                viewing_distance,
+            } = grid.scan(row, col, direction);
+            (visible_from_edge, viewing_distance)
+        };
+
+        // The middle 5 of the second row
+        assert_eq!(scan(1, 2, Direction::Up), (true, 1));
+        assert_eq!(scan(1, 2, Direction::Left), (false, 1));
+        assert_eq!(scan(1, 2, Direction::Right), (true, 2));
+        assert_eq!(scan(1, 2, Direction::Down), (false, 2));
+
+        // Edge trees see nothing in the direction of their edge, but are visible from it
+        assert_eq!(scan(0, 0, Direction::Up), (true, 0));
+        assert_eq!(grid.scenic_score(0, 0), 0);
+        assert!(!grid.is_visible(2, 2));
+    }
+
+    #[test]
+    fn rejects_malformed_grids() {
+        assert!(matches!(
+            "123\n1a3\n".parse::<TreeGrid>(),
+            Err(Day8Error::InvalidHeight {
+                line: 2,
+                column: 2,
+                found: 'a'
+            })
+        ));
+        assert!(matches!(
+            "123\n12\n".parse::<TreeGrid>(),
+            Err(Day8Error::RaggedRow {
+                line: 2,
+                expected: 3,
+                found: 2
+            })
+        ));
+        assert!(matches!(
+            "\n".parse::<TreeGrid>(),
+            Err(Day8Error::EmptyGrid)
+        ));
+    }
+}
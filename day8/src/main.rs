@@ -0,0 +1,24 @@
+use aoc_core::Solution as _;
+use color_eyre::eyre::Result;
+use day8::Day8;
+
+fn main() -> Result<()> {
+    #[cfg(feature = "trace")]
+    aoc_core::init_tracing();
+    color_eyre::install()?;
+
+    // Reads the puzzle input from the given path, or from stdin
+    let input = match std::env::args().nth(1) {
+        Some(path) if path != "-" => aoc_core::read_to_string(path)?,
+        _ => std::io::read_to_string(std::io::stdin().lock())?,
+    };
+    let grid = Day8::parse(&input)?;
+
+    println!(
+        "Step1: Trees visible from outside the grid: {}",
+        Day8::part1(&grid)?
+    );
+    println!("Step2: Highest scenic score: {}", Day8::part2(&grid)?);
+
+    Ok(())
+}
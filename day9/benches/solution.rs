@@ -0,0 +1,17 @@
+use aoc_core::Solution;
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use day9::Day9;
+
+/// Brief example repeated, so that the benches don't need the real puzzle input
+const FIXTURE: &str = include_str!("fixture.txt");
+
+fn solution(c: &mut Criterion) {
+    let input = Day9::parse(FIXTURE).unwrap();
+
+    c.bench_function("day9 parse", |b| b.iter(|| Day9::parse(black_box(FIXTURE))));
+    c.bench_function("day9 part1", |b| b.iter(|| Day9::part1(black_box(&input))));
+    c.bench_function("day9 part2", |b| b.iter(|| Day9::part2(black_box(&input))));
+}
+
+criterion_group!(benches, solution);
+criterion_main!(benches);
@@ -0,0 +1,272 @@
+use std::collections::HashSet;
+
+#[derive(Debug, thiserror::Error)]
+pub enum Day9Error {
+    #[error("Motion `{value}` on line {line} should look like `R 4`")]
+    MalformedMotion { line: usize, value: String },
+    #[error("Invalid direction `{value}` on line {line}, expected one of U, D, L or R")]
+    InvalidDirection { line: usize, value: String },
+    #[error("Invalid step count `{value}` on line {line}")]
+    InvalidSteps {
+        line: usize,
+        value: String,
+        #[source]
+        source: std::num::ParseIntError,
+    },
+    #[error("A rope needs at least one knot")]
+    NoKnots,
+}
+
+pub type Result<T, E = Day9Error> = std::result::Result<T, E>;
+
+/// Cell of the grid, as `(x, y)` with `y` growing upwards
+pub type Position = (i32, i32);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl Direction {
+    fn offset(&self) -> Position {
+        match self {
+            Self::Up => (0, 1),
+            Self::Down => (0, -1),
+            Self::Left => (-1, 0),
+            Self::Right => (1, 0),
+        }
+    }
+}
+
+/// Moves of the rope's head
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Motion {
+    pub direction: Direction,
+    pub steps: u32,
+}
+
+impl Motion {
+    fn parse(line: usize, value: &str) -> Result<Self> {
+        let (direction, steps) =
+            value
+                .trim()
+                .split_once(' ')
+                .ok_or_else(|| Day9Error::MalformedMotion {
+                    line,
+                    value: value.to_owned(),
+                })?;
+        let direction = match direction {
+            "U" => Direction::Up,
+            "D" => Direction::Down,
+            "L" => Direction::Left,
+            "R" => Direction::Right,
+            _ => {
+                return Err(Day9Error::InvalidDirection {
+                    line,
+                    value: direction.to_owned(),
+                })
+            }
+        };
+        let steps = steps
+            .trim()
+            .parse()
+            .map_err(|source| Day9Error::InvalidSteps {
+                line,
+                value: steps.to_owned(),
+                source,
+            })?;
+        Ok(Self { direction, steps })
+    }
+}
+
+/// Where a knot moves to once the knot ahead of it moved
+///
+/// Knots stay put while touching the one ahead, diagonals included, and otherwise take a single
+/// step towards it, diagonally when they aren't in the same row or column.
+pub fn follow(ahead: Position, knot: Position) -> Position {
+    let (dx, dy) = (ahead.0 - knot.0, ahead.1 - knot.1);
+    if dx.abs() <= 1 && dy.abs() <= 1 {
+        return knot;
+    }
+    (knot.0 + dx.signum(), knot.1 + dy.signum())
+}
+
+/// Knots of a rope, from its head to its tail
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rope(Vec<Position>);
+
+impl Rope {
+    /// Rope of `knots` knots, all of them starting on the same cell
+    pub fn new(knots: usize) -> Result<Self> {
+        if knots == 0 {
+            return Err(Day9Error::NoKnots);
+        }
+        Ok(Self(vec![(0, 0); knots]))
+    }
+
+    pub fn knots(&self) -> &[Position] {
+        &self.0
+    }
+
+    pub fn tail(&self) -> Position {
+        // Ropes cannot be built without knots
+        self.0[self.0.len() - 1]
+    }
+
+    /// Moves the head by a single step, all the other knots following
+    pub fn step(&mut self, direction: Direction) {
+        let (dx, dy) = direction.offset();
+        self.0[0] = (self.0[0].0 + dx, self.0[0].1 + dy);
+        for idx in 1..self.0.len() {
+            self.0[idx] = follow(self.0[idx - 1], self.0[idx]);
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Motions(pub Vec<Motion>);
+
+impl Motions {
+    /// Cells the tail of a `knots` knots rope visits at least once, the starting one included
+    pub fn tail_visits(&self, knots: usize) -> Result<usize> {
+        let mut rope = Rope::new(knots)?;
+        let mut visited: HashSet<Position> = HashSet::from([rope.tail()]);
+        for motion in &self.0 {
+            for _ in 0..motion.steps {
+                rope.step(motion.direction);
+                visited.insert(rope.tail());
+            }
+        }
+        Ok(visited.len())
+    }
+}
+
+impl std::str::FromStr for Motions {
+    type Err = Day9Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        s.lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty())
+            .map(|(idx, line)| Motion::parse(idx + 1, line))
+            .collect::<Result<Vec<_>>>()
+            .map(Self)
+    }
+}
+
+/// Day 9 puzzle: cells visited by the tail of a 2 knots rope, then of a 10 knots one
+#[cfg(feature = "eyre")]
+#[derive(Debug)]
+pub struct Day9;
+
+#[cfg(feature = "eyre")]
+impl aoc_core::Solution for Day9 {
+    type Input = Motions;
+
+    #[cfg_attr(
+        feature = "trace",
+        tracing::instrument(level = "debug", skip_all, err, fields(lines = input.lines().count()))
+    )]
+    fn parse(input: &str) -> color_eyre::eyre::Result<Self::Input> {
+        Ok(input.parse()?)
+    }
+
+    #[cfg_attr(
+        feature = "trace",
+        tracing::instrument(level = "debug", skip_all, ret, err)
+    )]
+    fn part1(motions: &Self::Input) -> color_eyre::eyre::Result<String> {
+        Ok(motions.tail_visits(2)?.to_string())
+    }
+
+    #[cfg_attr(
+        feature = "trace",
+        tracing::instrument(level = "debug", skip_all, ret, err)
+    )]
+    fn part2(motions: &Self::Input) -> color_eyre::eyre::Result<String> {
+        Ok(motions.tail_visits(10)?.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BRIEF_INPUT: &str = "R 4
+U 4
+L 3
+D 1
+R 4
+D 1
+L 5
+R 2
+";
+
+    const LARGER_BRIEF_INPUT: &str = "R 5
+U 8
+L 8
+D 3
+R 17
+D 10
+L 25
+U 20
+";
+
+    #[test]
+    fn conforms_to_brief_step1() {
+        let motions: Motions = BRIEF_INPUT.parse().unwrap();
+        assert_eq!(motions.0.len(), 8);
+        assert_eq!(motions.tail_visits(2).unwrap(), 13);
+    }
+
+    #[test]
+    fn conforms_to_brief_step2() {
+        let motions: Motions = BRIEF_INPUT.parse().unwrap();
+        assert_eq!(motions.tail_visits(10).unwrap(), 1);
+
+        let motions: Motions = LARGER_BRIEF_INPUT.parse().unwrap();
+        assert_eq!(motions.tail_visits(10).unwrap(), 36);
+    }
+
+    #[test]
+    fn knots_follow_the_one_ahead() {
+        // Touching, diagonals and overlap included
+        assert_eq!(follow((1, 1), (0, 0)), (0, 0));
+        assert_eq!(follow((0, 0), (0, 0)), (0, 0));
+        // Same row or column
+        assert_eq!(follow((2, 0), (0, 0)), (1, 0));
+        assert_eq!(follow((0, -2), (0, 0)), (0, -1));
+        // Diagonal catch-up
+        assert_eq!(follow((2, 1), (0, 0)), (1, 1));
+        assert_eq!(follow((-2, -2), (0, 0)), (-1, -1));
+
+        let mut rope = Rope::new(3).unwrap();
+        rope.step(Direction::Right);
+        rope.step(Direction::Right);
+        assert_eq!(rope.knots(), [(2, 0), (1, 0), (0, 0)]);
+        assert_eq!(Rope::new(1).unwrap().tail(), (0, 0));
+    }
+
+    #[test]
+    fn rejects_malformed_motions() {
+        assert!(matches!(
+            "R 4\nR4".parse::<Motions>(),
+            Err(Day9Error::MalformedMotion { line: 2, .. })
+        ));
+        assert!(matches!(
+            "X 4".parse::<Motions>(),
+            Err(Day9Error::InvalidDirection { line: 1, .. })
+        ));
+        assert!(matches!(
+            "R -4".parse::<Motions>(),
+            Err(Day9Error::InvalidSteps { line: 1, .. })
+        ));
+        assert!(matches!(
+            Motions::default().tail_visits(0),
+            Err(Day9Error::NoKnots)
+        ));
+    }
+}
@@ -0,0 +1,27 @@
+use aoc_core::Solution as _;
+use color_eyre::eyre::Result;
+use day9::Day9;
+
+fn main() -> Result<()> {
+    #[cfg(feature = "trace")]
+    aoc_core::init_tracing();
+    color_eyre::install()?;
+
+    // Reads the puzzle input from the given path, or from stdin
+    let input = match std::env::args().nth(1) {
+        Some(path) if path != "-" => aoc_core::read_to_string(path)?,
+        _ => std::io::read_to_string(std::io::stdin().lock())?,
+    };
+    let motions = Day9::parse(&input)?;
+
+    println!(
+        "Step1: Cells visited by the tail of a 2 knots rope: {}",
+        Day9::part1(&motions)?
+    );
+    println!(
+        "Step2: Cells visited by the tail of a 10 knots rope: {}",
+        Day9::part2(&motions)?
+    );
+
+    Ok(())
+}